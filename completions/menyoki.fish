@@ -15,10 +15,26 @@ complete -c menyoki -n "__fish_use_subcommand" -f -a "misc" -d 'Perfom miscellan
 complete -c menyoki -n "__fish_use_subcommand" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l action-keys -d 'Set the action keys'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l cancel-keys -d 'Set the cancel keys'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l marker-keys -d 'Set the keys for adding chapter markers'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s b -l border -d 'Set the border width'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s p -l padding -d 'Set the record area padding'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s s -l size -d 'Set the record area size'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s d -l duration -d 'Set the duration for recording'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l show-keys -d 'Show the pressed keys on the recording' -r -f -a "top-left top-right bottom-left bottom-right"
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l events -d 'Write the input events of the frames to a file'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l steps -d 'Record a step on each click or action key (e.g. 2s)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l step-dir -d 'Save the steps as images with a Markdown index'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l segment -d 'Split the recording into segments (e.g. 60s, 5m)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l tty-size -d 'Set the size of the terminal'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l tty-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l tty-theme -d 'Set the color theme of the terminal' -r -f -a "dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l virtual -d 'Record the command inside a virtual X display'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l windows -d 'Record multiple windows by ID or name (e.g. 0x1e00004,xterm)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l start-on -d 'Start recording on a trigger (window:NAME, change)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l stop-on -d 'Stop recording on a trigger (close, idle:T, file:PATH)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l every -d 'Capture an image periodically (e.g. 10s, 5m)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l count -d 'Set the number of images to capture periodically'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l assemble -d 'Assemble the captured images into an animation' -r -f -a "gif apng webp y4m avi tiff pdf"
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s c -l countdown -d 'Set the countdown before recording'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s t -l timeout -d 'Set the timeout for window selection'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s i -l interval -d 'Set the refresh interval for window selection'
@@ -31,22 +47,51 @@ complete -c menyoki -n "__fish_seen_subcommand_from record" -l parent -d 'Record
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l with-alpha -d 'Record with the alpha channel'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l no-keys -d 'Disable the action keys while recording'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s m -l mouse -d 'Select the window with mouse click'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l show-clicks -d 'Show the mouse clicks on the recording'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l tty -d 'Record the command in a terminal without a display server'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l compose -d 'Compose the recorded windows side by side'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "gif" -d 'Use the GIF encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "apng" -d 'Use the APNG encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "webp" -d 'Use the WebP encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "y4m" -d 'Use the Y4M encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "avi" -d 'Use the AVI (MJPEG) encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "tiff" -d 'Use the TIFF encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "pdf" -d 'Use the PDF encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "exec" -d 'Use an external encoder command'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "save" -d 'Save the output file(s)'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l max-size -d 'Tune the encoding to fit the output into the size'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from gif" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l jpeg -d 'Embed the pages as JPEG'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s n -l no-sort -d 'Use frames in the order given'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s V -l version -d 'Prints version information'
@@ -62,13 +107,33 @@ complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Pr
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l max-size -d 'Tune the encoding to fit the output into the size'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from apng" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l jpeg -d 'Embed the pages as JPEG'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s n -l no-sort -d 'Use frames in the order given'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s V -l version -d 'Prints version information'
@@ -81,6 +146,258 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l compression -d 'Set the compression level' -r -f -a "none lzw deflate packbits"
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
@@ -89,6 +406,7 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Pr
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from split" -s d -l dir -d 'Set the output directory'
+complete -c menyoki -n "__fish_seen_subcommand_from split" -l markers -d 'Split the animation at the chapter markers'
 complete -c menyoki -n "__fish_seen_subcommand_from split" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from split" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from split" -f -a "png" -d 'Use the PNG encoder'
@@ -137,6 +455,9 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l sizes -d 'Set the sizes of the icons'
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l filter -d 'Set the sampling filter for scaling' -r -f -a "nearest triangle catmull-rom gaussian lanczos3"
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l favicon-set -d 'Save the icon sizes as PNG along with the ICO'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -f -a "save" -d 'Save the output file(s)'
@@ -148,6 +469,7 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s c -l compression -d 'Set the compression method' -r -f -a "none lzw deflate packbits"
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -f -a "save" -d 'Save the output file(s)'
@@ -204,13 +526,33 @@ complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Pr
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle none lzw deflate packbits"
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l max-size -d 'Tune the encoding to fit the output into the size'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from make" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
 complete -c menyoki -n "__fish_seen_subcommand_from make" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l jpeg -d 'Embed the pages as JPEG'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s n -l no-sort -d 'Use frames in the order given'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s V -l version -d 'Prints version information'
@@ -225,10 +567,26 @@ complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l action-keys -d 'Set the action keys'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l cancel-keys -d 'Set the cancel keys'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l marker-keys -d 'Set the keys for adding chapter markers'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s b -l border -d 'Set the border width'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s p -l padding -d 'Set the capture area padding'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s s -l size -d 'Set the capture area size'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s d -l duration -d 'Set the duration for recording'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l show-keys -d 'Show the pressed keys on the recording' -r -f -a "top-left top-right bottom-left bottom-right"
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l events -d 'Write the input events of the frames to a file'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l steps -d 'Record a step on each click or action key (e.g. 2s)'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l step-dir -d 'Save the steps as images with a Markdown index'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l segment -d 'Split the recording into segments (e.g. 60s, 5m)'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l tty-size -d 'Set the size of the terminal'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l tty-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l tty-theme -d 'Set the color theme of the terminal' -r -f -a "dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l virtual -d 'Record the command inside a virtual X display'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l windows -d 'Record multiple windows by ID or name (e.g. 0x1e00004,xterm)'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l start-on -d 'Start recording on a trigger (window:NAME, change)'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l stop-on -d 'Stop recording on a trigger (close, idle:T, file:PATH)'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l every -d 'Capture an image periodically (e.g. 10s, 5m)'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l count -d 'Set the number of images to capture periodically'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l assemble -d 'Assemble the captured images into an animation' -r -f -a "gif apng webp y4m avi tiff pdf"
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s c -l countdown -d 'Set the countdown before capturing'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s t -l timeout -d 'Set the timeout for window selection'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s i -l interval -d 'Set the refresh interval for window selection'
@@ -241,6 +599,9 @@ complete -c menyoki -n "__fish_seen_subcommand_from capture" -l parent -d 'Captu
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l with-alpha -d 'Capture with the alpha channel'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l no-keys -d 'Disable the action keys while recording'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s m -l mouse -d 'Select the window with mouse click'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l show-clicks -d 'Show the mouse clicks on the recording'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l tty -d 'Record the command in a terminal without a display server'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l compose -d 'Compose the recorded windows side by side'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -f -a "png" -d 'Use the PNG encoder'
@@ -289,6 +650,9 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l sizes -d 'Set the sizes of the icons'
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l filter -d 'Set the sampling filter for scaling' -r -f -a "nearest triangle catmull-rom gaussian lanczos3"
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l favicon-set -d 'Save the icon sizes as PNG along with the ICO'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -f -a "save" -d 'Save the output file(s)'
@@ -300,6 +664,7 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s c -l compression -d 'Set the compression method' -r -f -a "none lzw deflate packbits"
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -f -a "save" -d 'Save the output file(s)'
@@ -363,6 +728,7 @@ complete -c menyoki -n "__fish_seen_subcommand_from edit" -l hue -d 'Adjust the
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -l contrast -d 'Adjust the contrast of the image'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -l brightness -d 'Adjust the brightness of the image'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -l filter -d 'Set the sampling filter for scaling' -r -f -a "nearest triangle catmull-rom gaussian lanczos3"
+complete -c menyoki -n "__fish_seen_subcommand_from edit" -l marker-range -d 'Keep the frames between the chapter markers'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -l convert -d 'Convert image using the given encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -l grayscale -d 'Convert image to grayscale'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -l invert -d 'Invert the colors of the image'
@@ -370,6 +736,11 @@ complete -c menyoki -n "__fish_seen_subcommand_from edit" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "gif" -d 'Use the GIF encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "apng" -d 'Use the APNG encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "webp" -d 'Use the WebP encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "y4m" -d 'Use the Y4M encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "avi" -d 'Use the AVI (MJPEG) encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "pdf" -d 'Use the PDF encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "exec" -d 'Use an external encoder command'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "png" -d 'Use the PNG encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "jpg" -d 'Use the JPG encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "bmp" -d 'Use the BMP encoder'
@@ -383,13 +754,33 @@ complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "help" -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l max-size -d 'Tune the encoding to fit the output into the size'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from gif" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l jpeg -d 'Embed the pages as JPEG'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s n -l no-sort -d 'Use frames in the order given'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s V -l version -d 'Prints version information'
@@ -405,13 +796,33 @@ complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Pr
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l max-size -d 'Tune the encoding to fit the output into the size'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from apng" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l jpeg -d 'Embed the pages as JPEG'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s n -l no-sort -d 'Use frames in the order given'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s V -l version -d 'Prints version information'
@@ -424,6 +835,216 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from webp" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from y4m" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from pdf" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l colors -d 'Set the maximum number of colors (2-256)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l dither -d 'Set the dithering method' -r -f -a "none bayer floyd-steinberg"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l alpha-threshold -d 'Set the alpha value below which pixels are transparent'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l chroma -d 'Set the chroma subsampling' -r -f -a "420 444"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l page-size -d 'Set the page size' -r -f -a "fit a4 letter"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l encoder-cmd -d 'Set the command to pipe the raw frames into'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l dedupe -d 'Drop duplicate frames and merge their delays'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l preset -d 'Use an encoding preset (chat, docs, hq, tiny)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l max-size -d 'Tune the encoding to fit the output into the size'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l format -d 'Set the animation format' -r -f -a "gif apng webp y4m avi tiff pdf exec"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cast -d 'Render an asciicast recording'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l idle-limit -d 'Limit the idle time between the cast events'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cast-speed -d 'Set the playback speed of the cast'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cast-scale -d 'Set the scale of the terminal font'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l cast-theme -d 'Set the color theme of the terminal' -r -f -a "cast dark light"
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l global-palette -d 'Use a global palette generated from the frames'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l indexed -d 'Use an indexed palette when the colors fit'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l lossless -d 'Use the lossless compression'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -l jpeg -d 'Embed the pages as JPEG'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from exec" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from png" -s c -l compression -d 'Set the compression level' -r -f -a "default fast best huffman rle"
 complete -c menyoki -n "__fish_seen_subcommand_from png" -s f -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
 complete -c menyoki -n "__fish_seen_subcommand_from png" -s h -l help -d 'Print help information'
@@ -460,6 +1081,9 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l sizes -d 'Set the sizes of the icons'
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l filter -d 'Set the sampling filter for scaling' -r -f -a "nearest triangle catmull-rom gaussian lanczos3"
+complete -c menyoki -n "__fish_seen_subcommand_from ico" -l favicon-set -d 'Save the icon sizes as PNG along with the ICO'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from ico" -f -a "save" -d 'Save the output file(s)'
@@ -471,6 +1095,7 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s c -l compression -d 'Set the compression method' -r -f -a "none lzw deflate packbits"
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from tiff" -f -a "save" -d 'Save the output file(s)'
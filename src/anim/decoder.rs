@@ -1,15 +1,17 @@
+use crate::anim::marker::Markers;
 use crate::anim::settings::AnimSettings;
 use crate::anim::Frames;
 use crate::app::{AppError, AppResult};
 use crate::edit::ImageOps;
 use image::Frame;
-use std::convert::TryInto;
 use std::io::{self, Write};
+use std::ops::Range;
 
 /* Animation decoder and settings */
 pub struct AnimDecoder<'a> {
 	imageops: ImageOps<'a>,
 	settings: &'a AnimSettings,
+	markers: Markers,
 }
 
 impl<'a> AnimDecoder<'a> {
@@ -21,35 +23,40 @@ impl<'a> AnimDecoder<'a> {
 	 * @return AnimDecoder
 	 */
 	pub fn new(imageops: ImageOps<'a>, settings: &'a AnimSettings) -> Self {
-		Self { imageops, settings }
+		Self {
+			imageops,
+			settings,
+			markers: Markers::default(),
+		}
 	}
 
 	/**
-	 * Update frames to cut the duration.
+	 * Set the chapter markers of the frames.
 	 *
-	 * @param  frames
+	 * @param  markers
+	 * @return AnimDecoder
+	 */
+	pub fn with_markers(mut self, markers: Markers) -> Self {
+		self.markers = markers;
+		self
+	}
+
+	/**
+	 * Get the range of frames to keep after cutting the duration.
+	 *
+	 * @param  frame_count
 	 * @param  cut
 	 * @param  fps
-	 * @return Vector of Frame
+	 * @return Range
 	 */
-	fn cut_duration(
-		frames: &mut Vec<Frame>,
-		cut: (f32, f32),
-		fps: u32,
-	) -> Vec<Frame> {
+	fn get_cut_range(frame_count: usize, cut: (f32, f32), fps: u32) -> Range<usize> {
 		if cut != (0., 0.) {
 			let (start, end) = cut;
 			let frame_delay = 1000_u32.checked_div(fps).unwrap_or_default() as f32;
-			frames
-				.drain(
-					((start / frame_delay) as u32)
-						.try_into()
-						.unwrap_or_default()
-						..frames.len().saturating_sub((end / frame_delay) as usize),
-				)
-				.collect()
+			let end = frame_count.saturating_sub((end / frame_delay) as usize);
+			((start / frame_delay) as usize).min(end)..end
 		} else {
-			frames.to_vec()
+			0..frame_count
 		}
 	}
 
@@ -68,7 +75,9 @@ impl<'a> AnimDecoder<'a> {
 		let fps = ((1e3 / first_frame.delay().numer_denom_ms().0 as f32)
 			* self.settings.speed) as u32;
 		debug!("FPS: {:?}", fps);
-		let frames = Self::cut_duration(&mut frames, self.settings.cut, fps);
+		let range = Self::get_cut_range(frames.len(), self.settings.cut, fps);
+		let markers = self.markers.rebase(range.clone());
+		let frames = frames.drain(range).collect::<Vec<Frame>>();
 		let mut images = Vec::new();
 		for (i, frame) in frames.iter().enumerate() {
 			let percentage = ((i + 1) as f64 / frames.len() as f64) * 100.;
@@ -80,11 +89,12 @@ impl<'a> AnimDecoder<'a> {
 				frames.len()
 			);
 			io::stdout().flush()?;
-			images.push(
-				self.imageops
-					.process(frame.clone().into_buffer())
-					.get_image(),
-			);
+			let mut image = self
+				.imageops
+				.process(frame.clone().into_buffer())
+				.get_image();
+			image.marker = markers.contains(i);
+			images.push(image);
 		}
 		info!("\n");
 		Ok((images, fps))
@@ -106,6 +116,7 @@ mod tests {
 		let mut edit_settings = EditSettings::default();
 		edit_settings.image.ratio = 2.0;
 		let frames = AnimDecoder::new(edit_settings.get_imageops(), &anim_settings)
+			.with_markers(Markers::new(vec![1]))
 			.update_frames(vec![
 				Frame::from_parts(
 					RgbaImage::new(1, 1),
@@ -124,5 +135,10 @@ mod tests {
 		assert_eq!(2, frames.1);
 		assert_eq!(1, frames.0.len());
 		assert_eq!(Geometry::new(0, 0, 2, 2), frames.0[0].geometry);
+		assert!(frames.0[0].marker);
+		assert_eq!(0..2, AnimDecoder::get_cut_range(2, (0., 0.), 1));
+		assert_eq!(1..2, AnimDecoder::get_cut_range(3, (1000., 1000.), 1));
+		assert_eq!(1..1, AnimDecoder::get_cut_range(2, (2000., 1000.), 1));
+		assert_eq!(0..0, AnimDecoder::get_cut_range(2, (5000., 3000.), 1));
 	}
 }
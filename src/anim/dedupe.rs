@@ -0,0 +1,101 @@
+use crate::anim::Frames;
+use crate::image::Image;
+
+/* Duplicate frame remover */
+#[derive(Clone, Copy, Debug)]
+pub struct FrameDeduper {
+	threshold: f64,
+}
+
+impl FrameDeduper {
+	/**
+	 * Create a new FrameDeduper object.
+	 *
+	 * @param  threshold
+	 * @return FrameDeduper
+	 */
+	pub fn new(threshold: f64) -> Self {
+		Self { threshold }
+	}
+
+	/**
+	 * Check if the given images are considered as duplicates.
+	 *
+	 * @param  previous
+	 * @param  image
+	 * @return bool
+	 */
+	fn is_duplicate(&self, previous: &Image, image: &Image) -> bool {
+		let difference = previous.get_difference(image);
+		if self.threshold > 0. {
+			difference <= self.threshold
+		} else {
+			difference == 0.
+		}
+	}
+
+	/**
+	 * Drop the consecutive duplicate frames and merge their delays.
+	 *
+	 * @param  frames
+	 * @return Frames
+	 */
+	pub fn dedupe(&self, frames: Frames) -> Frames {
+		let (images, fps) = frames;
+		let frame_delay = (1e3 / fps as f64).round() as u32;
+		let frame_count = images.len();
+		let mut deduped: Vec<Image> = Vec::new();
+		for image in images {
+			match deduped.last_mut() {
+				Some(previous) if self.is_duplicate(previous, &image) => {
+					previous.delay = Some(
+						previous.delay.unwrap_or(frame_delay)
+							+ image.delay.unwrap_or(frame_delay),
+					);
+					previous.marker |= image.marker;
+				}
+				_ => deduped.push(image),
+			}
+		}
+		info!(
+			"Dropped {} duplicate frames. ({} -> {})",
+			frame_count - deduped.len(),
+			frame_count,
+			deduped.len()
+		);
+		(deduped, fps)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::Bgra;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_frame_deduper() {
+		let geometry = Geometry::new(0, 0, 2, 1);
+		let black = Bgra::from([0, 0, 0, 0]);
+		let white = Bgra::from([255, 255, 255, 0]);
+		let images = vec![
+			Image::new(vec![black, black], false, geometry),
+			Image::new(vec![black, black], false, geometry),
+			Image::new(vec![black, white], false, geometry),
+			Image::new(vec![white, white], false, geometry),
+			Image::new(vec![white, white], false, geometry),
+			Image::new(vec![white, white], false, geometry),
+		];
+		let (frames, fps) = FrameDeduper::new(0.).dedupe((images.clone(), 10));
+		assert_eq!(10, fps);
+		assert_eq!(
+			vec![Some(200), None, Some(300)],
+			frames.iter().map(|v| v.delay).collect::<Vec<Option<u32>>>()
+		);
+		let (frames, _) = FrameDeduper::new(50.).dedupe((images, 10));
+		assert_eq!(
+			vec![Some(300), Some(300)],
+			frames.iter().map(|v| v.delay).collect::<Vec<Option<u32>>>()
+		);
+	}
+}
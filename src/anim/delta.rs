@@ -0,0 +1,152 @@
+use crate::image::geometry::Geometry;
+use std::convert::TryInto;
+
+/* Number of bytes per RGBA pixel */
+const CHANNELS: usize = 4;
+
+/* Changed area of a frame compared to the previous frame */
+#[derive(Debug)]
+pub struct FrameDelta {
+	pub area: Geometry,
+	pub pixels: Vec<Option<[u8; CHANNELS]>>,
+}
+
+impl FrameDelta {
+	/**
+	 * Make the pixels below the alpha threshold transparent and the rest opaque.
+	 *
+	 * @param  data
+	 * @param  threshold
+	 */
+	pub fn apply_alpha_threshold(data: &mut [u8], threshold: u8) {
+		for pixel in data.chunks_mut(CHANNELS) {
+			if pixel[3] < threshold {
+				pixel.copy_from_slice(&[0; CHANNELS]);
+			} else {
+				pixel[3] = u8::MAX;
+			}
+		}
+	}
+
+	/**
+	 * Check if the current frame can be drawn over the previous frame.
+	 *
+	 * @param  previous
+	 * @param  current
+	 * @return bool
+	 */
+	pub fn can_follow(previous: &[u8], current: &[u8]) -> bool {
+		previous.len() == current.len()
+			&& previous
+				.chunks(CHANNELS)
+				.zip(current.chunks(CHANNELS))
+				.all(|(old, new)| new[3] != 0 || old[3] == 0)
+	}
+
+	/**
+	 * Create a FrameDelta object that covers the whole frame.
+	 *
+	 * @param  data
+	 * @param  geometry
+	 * @return FrameDelta
+	 */
+	pub fn from_data(data: &[u8], geometry: Geometry) -> Self {
+		Self {
+			area: Geometry::new(0, 0, geometry.width, geometry.height),
+			pixels: data
+				.chunks(CHANNELS)
+				.map(|pixel| match pixel {
+					[_, _, _, 0] => None,
+					[r, g, b, _] => Some([*r, *g, *b, u8::MAX]),
+					_ => None,
+				})
+				.collect(),
+		}
+	}
+
+	/**
+	 * Create a new FrameDelta object from the RGBA data of frames.
+	 *
+	 * @param  previous
+	 * @param  current
+	 * @param  width
+	 * @return FrameDelta
+	 */
+	pub fn new(previous: &[u8], current: &[u8], width: u32) -> Self {
+		let width = width.max(1) as usize;
+		let mut bounds: Option<(usize, usize, usize, usize)> = None;
+		for (i, (old, new)) in previous
+			.chunks(CHANNELS)
+			.zip(current.chunks(CHANNELS))
+			.enumerate()
+		{
+			if old != new {
+				let (x, y) = (i % width, i / width);
+				bounds = Some(match bounds {
+					Some((x1, y1, x2, y2)) => {
+						(x1.min(x), y1.min(y), x2.max(x), y2.max(y))
+					}
+					None => (x, y, x, y),
+				});
+			}
+		}
+		match bounds {
+			Some((x1, y1, x2, y2)) => {
+				let mut pixels = Vec::new();
+				for y in y1..=y2 {
+					for x in x1..=x2 {
+						let i = (y * width + x) * CHANNELS;
+						let pixel = &current[i..i + CHANNELS];
+						pixels.push(if pixel != &previous[i..i + CHANNELS] {
+							pixel.try_into().ok()
+						} else {
+							None
+						});
+					}
+				}
+				Self {
+					area: Geometry::new(
+						x1 as i32,
+						y1 as i32,
+						(x2 - x1 + 1) as u32,
+						(y2 - y1 + 1) as u32,
+					),
+					pixels,
+				}
+			}
+			None => Self {
+				area: Geometry::new(0, 0, 1, 1),
+				pixels: vec![None],
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_frame_delta() {
+		let previous = vec![0; 4 * 4 * CHANNELS];
+		let mut current = previous.clone();
+		current[5 * CHANNELS..6 * CHANNELS].copy_from_slice(&[255; CHANNELS]);
+		current[10 * CHANNELS..11 * CHANNELS].copy_from_slice(&[128; CHANNELS]);
+		let delta = FrameDelta::new(&previous, &current, 4);
+		assert_eq!(Geometry::new(1, 1, 2, 2), delta.area);
+		assert_eq!(
+			vec![Some([255; CHANNELS]), None, None, Some([128; CHANNELS])],
+			delta.pixels
+		);
+		let delta = FrameDelta::new(&previous, &previous, 4);
+		assert_eq!(Geometry::new(0, 0, 1, 1), delta.area);
+		assert_eq!(vec![None], delta.pixels);
+		current[0..CHANNELS].copy_from_slice(&[10, 20, 30, 0]);
+		let delta = FrameDelta::from_data(&current, Geometry::new(5, 5, 4, 4));
+		assert_eq!(Geometry::new(0, 0, 4, 4), delta.area);
+		assert_eq!(
+			(None, Some([255; CHANNELS])),
+			(delta.pixels[0], delta.pixels[5])
+		);
+	}
+}
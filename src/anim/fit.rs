@@ -0,0 +1,240 @@
+use crate::anim::Frames;
+use crate::app::{AppError, AppResult};
+use bytesize::ByteSize;
+use image::imageops::FilterType;
+use std::collections::HashMap;
+
+/* Lowest values of the encoding parameters to try */
+const MIN_QUALITY: u8 = 10;
+const MIN_SCALE: f32 = 0.2;
+const MAX_FRAME_STEP: usize = 4;
+
+/* Encoding parameters of a trial pass */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitParams {
+	pub quality: Option<u8>,
+	pub step: usize,
+	pub scale: f32,
+}
+
+/* Default initialization values for FitParams */
+impl Default for FitParams {
+	fn default() -> Self {
+		Self {
+			quality: None,
+			step: 1,
+			scale: 1.,
+		}
+	}
+}
+
+impl FitParams {
+	/**
+	 * Get the next parameters that reduce the output size.
+	 *
+	 * @param  index
+	 * @return FitParams (Option)
+	 */
+	fn reduce(&self, index: usize) -> Option<Self> {
+		let mut params = *self;
+		match index % 3 {
+			0 => {
+				params.quality = self
+					.quality
+					.map(|quality| ((quality as f32 * 0.6) as u8).max(MIN_QUALITY))
+			}
+			1 => params.scale = (self.scale * 0.8).max(MIN_SCALE),
+			_ => params.step = (self.step + 1).min(MAX_FRAME_STEP),
+		}
+		if params != *self {
+			Some(params)
+		} else {
+			None
+		}
+	}
+
+	/**
+	 * Apply the parameters to the given frames.
+	 *
+	 * @param  frames
+	 * @return Frames
+	 */
+	pub fn apply(&self, frames: &Frames) -> Frames {
+		let (images, fps) = frames;
+		let frame_delay = (1e3 / *fps as f64).round() as u32;
+		let images = images
+			.chunks(self.step)
+			.map(|chunk| {
+				let mut image = chunk[0].resize(self.scale, FilterType::Triangle);
+				if self.step > 1 {
+					image.delay = Some(
+						chunk
+							.iter()
+							.map(|image| image.delay.unwrap_or(frame_delay))
+							.sum(),
+					);
+					image.marker = chunk.iter().any(|image| image.marker);
+				}
+				image
+			})
+			.collect();
+		(images, *fps)
+	}
+}
+
+/* Searcher for the encoding parameters that fit the output size */
+#[derive(Debug)]
+pub struct SizeFitter {
+	max_size: u64,
+	candidates: Vec<FitParams>,
+}
+
+impl SizeFitter {
+	/**
+	 * Create a new SizeFitter object.
+	 *
+	 * @param  max_size
+	 * @param  quality (Option)
+	 * @return SizeFitter
+	 */
+	pub fn new(max_size: u64, quality: Option<u8>) -> Self {
+		let mut params = FitParams {
+			quality,
+			..FitParams::default()
+		};
+		let mut candidates = vec![params];
+		let mut index = 0;
+		while let Some(next) =
+			(index..index + 3).find_map(|i| params.reduce(i).map(|next| (i, next)))
+		{
+			index = next.0 + 1;
+			params = next.1;
+			candidates.push(params);
+		}
+		Self {
+			max_size,
+			candidates,
+		}
+	}
+
+	/**
+	 * Search the parameters that produce an output within the size limit.
+	 *
+	 * @param  frames
+	 * @param  encode
+	 * @return Tuple of FitParams and encoded data (Result)
+	 */
+	pub fn fit<Encode>(
+		&self,
+		frames: &Frames,
+		mut encode: Encode,
+	) -> AppResult<(FitParams, Vec<u8>)>
+	where
+		Encode: FnMut(Frames, FitParams) -> AppResult<Vec<u8>>,
+	{
+		let mut results = HashMap::new();
+		let mut trial = |index: usize| -> AppResult<bool> {
+			let params = self.candidates[index];
+			info!(
+				"Trying to fit the output into {}... [{}/{}]",
+				ByteSize(self.max_size),
+				index + 1,
+				self.candidates.len()
+			);
+			debug!("{:?}", params);
+			let data = encode(params.apply(frames), params)?;
+			let fits = data.len() as u64 <= self.max_size;
+			debug!(
+				"Output size: {} (fits: {})",
+				ByteSize(data.len() as u64),
+				fits
+			);
+			results.insert(index, data);
+			Ok(fits)
+		};
+		let last = self.candidates.len() - 1;
+		let index = if trial(0)? {
+			0
+		} else {
+			let (mut low, mut high) = (1, last);
+			let mut fitted = None;
+			while low <= high {
+				let middle = (low + high) / 2;
+				if trial(middle)? {
+					fitted = Some(middle);
+					high = middle - 1;
+				} else {
+					low = middle + 1;
+				}
+			}
+			match fitted {
+				Some(index) => index,
+				None => {
+					warn!(
+						"Failed to fit the output into {}.",
+						ByteSize(self.max_size)
+					);
+					last
+				}
+			}
+		};
+		let data = results.remove(&index).ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to encode the frames"))
+		})?;
+		Ok((self.candidates[index], data))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use crate::image::Image;
+	use image::{Bgra, ExtendedColorType};
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_size_fitter() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 10, 10);
+		let mut images = Vec::new();
+		for i in 0..5 {
+			let mut image =
+				Image::new(vec![Bgra::from([i, i, i, 255]); 100], false, geometry);
+			image.marker = i == 1;
+			images.push(image);
+		}
+		let frames = (images, 10);
+		let fitter = SizeFitter::new(400, Some(75));
+		assert_eq!(
+			FitParams {
+				quality: Some(MIN_QUALITY),
+				step: MAX_FRAME_STEP,
+				scale: MIN_SCALE,
+			},
+			*fitter.candidates.last().unwrap()
+		);
+		let (params, data) = fitter.fit(&frames, |frames, _| {
+			Ok(frames
+				.0
+				.iter()
+				.flat_map(|image| image.get_data(ExtendedColorType::Rgb8))
+				.collect())
+		})?;
+		assert!(data.len() <= 400);
+		let (images, _) = params.apply(&frames);
+		assert_eq!(
+			data.len(),
+			images.len()
+				* (images[0].geometry.width * images[0].geometry.height) as usize
+				* 3
+		);
+		let (images, _) = FitParams {
+			step: 2,
+			..FitParams::default()
+		}
+		.apply(&frames);
+		assert_eq!(3, images.len());
+		assert_eq!((Some(200), true), (images[0].delay, images[0].marker));
+		assert_eq!(Some(100), images[2].delay);
+		Ok(())
+	}
+}
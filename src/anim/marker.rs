@@ -0,0 +1,228 @@
+use crate::app::{AppError, AppResult};
+use crate::image::Image;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/* Extension of the marker files */
+const MARKER_EXTENSION: &str = "markers";
+
+/* Chapter markers of an animation */
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Markers {
+	frames: Vec<usize>,
+}
+
+impl Markers {
+	/**
+	 * Create a new Markers object.
+	 *
+	 * @param  frames
+	 * @return Markers
+	 */
+	pub fn new(mut frames: Vec<usize>) -> Self {
+		frames.sort_unstable();
+		frames.dedup();
+		Self { frames }
+	}
+
+	/**
+	 * Create a Markers object from the marked images.
+	 *
+	 * @param  images
+	 * @return Markers
+	 */
+	pub fn from_images(images: &[Image]) -> Self {
+		Self::new(
+			images
+				.iter()
+				.enumerate()
+				.filter(|(_, image)| image.marker)
+				.map(|(i, _)| i)
+				.collect(),
+		)
+	}
+
+	/**
+	 * Get the path of the marker file for the given animation.
+	 *
+	 * @param  path
+	 * @return PathBuf
+	 */
+	pub fn get_path(path: &Path) -> PathBuf {
+		let mut file_name = path.file_name().map(OsString::from).unwrap_or_default();
+		file_name.push(format!(".{}", MARKER_EXTENSION));
+		path.with_file_name(file_name)
+	}
+
+	/**
+	 * Read the markers of the given animation.
+	 *
+	 * @param  path
+	 * @return Markers (Result)
+	 */
+	pub fn read(path: &Path) -> AppResult<Self> {
+		let path = Self::get_path(path);
+		match fs::read_to_string(&path) {
+			Ok(markers) => Ok(Self::new(
+				markers
+					.lines()
+					.filter_map(|line| line.trim().parse().ok())
+					.collect(),
+			)),
+			Err(e) if e.kind() == ErrorKind::NotFound => Err(AppError::FrameError(
+				format!("No markers file found: {:?}", path),
+			)),
+			Err(e) => Err(e.into()),
+		}
+	}
+
+	/**
+	 * Write the markers of the given animation if there is any.
+	 *
+	 * @param  path
+	 * @return Result
+	 */
+	pub fn write(&self, path: &Path) -> io::Result<()> {
+		if !self.frames.is_empty() {
+			let path = Self::get_path(path);
+			fs::write(
+				&path,
+				self.frames
+					.iter()
+					.map(|frame| format!("{}\n", frame))
+					.collect::<String>(),
+			)?;
+			info!("{} markers saved to: {:?}", self.frames.len(), path);
+		}
+		Ok(())
+	}
+
+	/**
+	 * Check if the given frame is marked.
+	 *
+	 * @param  frame
+	 * @return bool
+	 */
+	pub fn contains(&self, frame: usize) -> bool {
+		self.frames.binary_search(&frame).is_ok()
+	}
+
+	/**
+	 * Get the markers of the frames in the given range
+	 * with the indices relative to the start of the range.
+	 *
+	 * @param  range
+	 * @return Markers
+	 */
+	pub fn rebase(&self, range: Range<usize>) -> Self {
+		Self::new(
+			self.frames
+				.iter()
+				.filter(|frame| range.contains(frame))
+				.map(|frame| frame - range.start)
+				.collect(),
+		)
+	}
+
+	/**
+	 * Get the markers of the frames that are merged in the given steps.
+	 *
+	 * @param  step
+	 * @return Markers
+	 */
+	pub fn step(&self, step: usize) -> Self {
+		Self::new(
+			self.frames
+				.iter()
+				.map(|frame| frame / step.max(1))
+				.collect(),
+		)
+	}
+
+	/**
+	 * Check if there are no markers.
+	 *
+	 * @return bool
+	 */
+	pub fn is_empty(&self) -> bool {
+		self.frames.is_empty()
+	}
+
+	/**
+	 * Get the frame ranges between the markers.
+	 *
+	 * @param  frame_count
+	 * @return Vector of Range
+	 */
+	pub fn get_ranges(&self, frame_count: usize) -> Vec<Range<usize>> {
+		let mut bounds = vec![0];
+		bounds.extend(
+			self.frames
+				.iter()
+				.filter(|frame| **frame > 0 && **frame < frame_count),
+		);
+		bounds.push(frame_count);
+		bounds.windows(2).map(|v| v[0]..v[1]).collect()
+	}
+
+	/**
+	 * Get the frame range between the given markers.
+	 *
+	 * @param  range
+	 * @param  frame_count
+	 * @return Range
+	 */
+	pub fn get_range(
+		&self,
+		range: (usize, Option<usize>),
+		frame_count: usize,
+	) -> Range<usize> {
+		let ranges = self.get_ranges(frame_count);
+		let start = ranges.get(range.0).map_or(frame_count, |v| v.start);
+		let end = range
+			.1
+			.and_then(|end| ranges.get(end))
+			.map_or(frame_count, |v| v.start);
+		start..end.max(start)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_markers() {
+		let mut images =
+			vec![Image::new(Vec::new(), false, Geometry::default()); 10];
+		images[3].marker = true;
+		images[7].marker = true;
+		let markers = Markers::from_images(&images);
+		assert_eq!(Markers::new(vec![7, 3, 7]), markers);
+		assert_eq!(vec![0..3, 3..7, 7..10], markers.get_ranges(10));
+		assert_eq!(vec![0..3, 3..5], markers.get_ranges(5));
+		assert_eq!(3..7, markers.get_range((1, Some(2)), 10));
+		assert_eq!(3..10, markers.get_range((1, None), 10));
+		assert_eq!(0..3, markers.get_range((0, Some(1)), 10));
+		assert_eq!(10..10, markers.get_range((5, None), 10));
+		assert_eq!(
+			PathBuf::from("test/anim.gif.markers"),
+			Markers::get_path(Path::new("test/anim.gif"))
+		);
+		assert!(Markers::default().is_empty());
+		assert!(markers.contains(3));
+		assert!(!markers.contains(4));
+		assert_eq!(Markers::new(vec![1]), markers.rebase(2..5));
+		assert_eq!(Markers::new(vec![0, 4]), markers.rebase(3..10));
+		assert_eq!(Markers::new(vec![1, 2]), markers.step(3));
+		assert_eq!(markers, markers.step(1));
+		assert!(matches!(
+			Markers::read(Path::new("test/anim.gif")),
+			Err(AppError::FrameError(_))
+		));
+	}
+}
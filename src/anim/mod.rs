@@ -1,4 +1,9 @@
 pub mod decoder;
+pub mod dedupe;
+pub mod delta;
+pub mod fit;
+pub mod marker;
+pub mod preset;
 pub mod settings;
 
 use crate::image::Image;
@@ -12,6 +17,12 @@ pub type Frames = (Vec<Image>, u32);
 pub enum AnimFormat {
 	Gif,
 	Apng,
+	WebP,
+	Y4m,
+	Avi,
+	Tiff,
+	Pdf,
+	Exec,
 }
 
 /* Display implementation for user-facing output */
@@ -50,6 +61,18 @@ impl AnimMode {
 		}
 	}
 
+	/* Check if the animation mode supports the options of a format.
+	 *
+	 * @param  format
+	 * @return bool
+	 */
+	pub fn supports(&self, format: AnimFormat) -> bool {
+		match self {
+			Self::Record(f) | Self::Edit(f) => f == &format,
+			_ => true,
+		}
+	}
+
 	/* Get the mode description.
 	 *
 	 * @return str
@@ -59,6 +82,12 @@ impl AnimMode {
 			Self::Record(format) | Self::Edit(format) => match format {
 				AnimFormat::Gif => "Use the GIF encoder",
 				AnimFormat::Apng => "Use the APNG encoder",
+				AnimFormat::WebP => "Use the WebP encoder",
+				AnimFormat::Y4m => "Use the Y4M encoder",
+				AnimFormat::Avi => "Use the AVI (MJPEG) encoder",
+				AnimFormat::Tiff => "Use the TIFF encoder",
+				AnimFormat::Pdf => "Use the PDF encoder",
+				AnimFormat::Exec => "Use an external encoder command",
 			},
 			Self::Make => "Make an animation from frames",
 		}
@@ -96,11 +125,17 @@ mod tests {
 		let anim_mode = AnimMode::Edit(AnimFormat::Gif);
 		assert!(anim_mode.is_edit());
 		assert!(anim_mode.has_format(AnimFormat::Gif));
+		assert!(!anim_mode.supports(AnimFormat::WebP));
 		assert_eq!("Use the GIF encoder", anim_mode.get_description());
 		assert_eq!("gif", anim_mode.to_string().as_str());
+		let anim_mode = AnimMode::Record(AnimFormat::WebP);
+		assert!(anim_mode.supports(AnimFormat::WebP));
+		assert_eq!("Use the WebP encoder", anim_mode.get_description());
+		assert_eq!("webp", anim_mode.to_string().as_str());
 		let anim_mode = AnimMode::Make;
 		assert!(!anim_mode.is_edit());
 		assert!(!anim_mode.has_format(AnimFormat::Apng));
+		assert!(anim_mode.supports(AnimFormat::Apng));
 		assert_eq!("Make an animation from frames", anim_mode.get_description());
 		assert_eq!("make", anim_mode.to_string().as_str());
 	}
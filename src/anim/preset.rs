@@ -0,0 +1,149 @@
+use crate::anim::settings::AnimSettings;
+use crate::args::matches::ArgMatches;
+use crate::edit::settings::EditSettings;
+use ini::Ini as Config;
+
+/* Prefix of the config sections that define presets */
+const SECTION_PREFIX: &str = "preset.";
+
+/* Values of a built-in preset (name, FPS, quality, scale, dedupe, gifski) */
+type PresetValues = (&'static str, u32, u8, f32, Option<f64>, bool);
+
+/* Built-in presets */
+const PRESETS: [PresetValues; 4] = [
+	("chat", 15, 75, 0.75, Some(0.), false),
+	("docs", 12, 90, 1., Some(0.), true),
+	("hq", 30, 100, 1., None, true),
+	("tiny", 8, 50, 0.5, Some(1.), false),
+];
+
+/* Named bundle of animation encoding settings */
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnimPreset {
+	pub name: String,
+	pub fps: Option<u32>,
+	pub quality: Option<u8>,
+	pub scale: Option<f32>,
+	pub dedupe: Option<f64>,
+	pub gifski: Option<bool>,
+}
+
+impl AnimPreset {
+	/**
+	 * Get the preset with the given name from config or built-in presets.
+	 *
+	 * @param  name
+	 * @param  config (Option)
+	 * @return AnimPreset (Option)
+	 */
+	pub fn get(name: &str, config: Option<&Config>) -> Option<Self> {
+		config
+			.and_then(|config| Self::from_config(name, config))
+			.or_else(|| Self::from_builtin(name))
+	}
+
+	/**
+	 * Get the built-in preset with the given name.
+	 *
+	 * @param  name
+	 * @return AnimPreset (Option)
+	 */
+	fn from_builtin(name: &str) -> Option<Self> {
+		PRESETS.iter().find(|preset| preset.0 == name).map(
+			|(name, fps, quality, scale, dedupe, gifski)| Self {
+				name: name.to_string(),
+				fps: Some(*fps),
+				quality: Some(*quality),
+				scale: Some(*scale),
+				dedupe: *dedupe,
+				gifski: Some(*gifski),
+			},
+		)
+	}
+
+	/**
+	 * Get the preset with the given name from a config section.
+	 *
+	 * @param  name
+	 * @param  config
+	 * @return AnimPreset (Option)
+	 */
+	fn from_config(name: &str, config: &Config) -> Option<Self> {
+		let section = config.section(Some(format!("{}{}", SECTION_PREFIX, name)))?;
+		Some(Self {
+			name: name.to_string(),
+			fps: section.get("fps").and_then(|v| v.parse().ok()),
+			quality: section.get("quality").and_then(|v| v.parse().ok()),
+			scale: section.get("scale").and_then(|v| v.parse().ok()),
+			dedupe: section.get("dedupe").and_then(|v| v.parse().ok()),
+			gifski: section.get("gifski").and_then(|v| v.parse().ok()),
+		})
+	}
+
+	/**
+	 * Apply the preset unless the values are given explicitly.
+	 *
+	 * @param  anim_args (Option)
+	 * @param  edit_args (Option)
+	 * @param  anim
+	 * @param  edit
+	 */
+	pub fn apply(
+		&self,
+		anim_args: Option<&ArgMatches<'_>>,
+		edit_args: Option<&ArgMatches<'_>>,
+		anim: &mut AnimSettings,
+		edit: &mut EditSettings,
+	) {
+		let is_explicit = |args: Option<&ArgMatches<'_>>, name: &str| {
+			args.map_or(false, |args| args.is_explicit(name))
+		};
+		if let Some(fps) = self.fps.filter(|_| !is_explicit(anim_args, "fps")) {
+			anim.fps = fps.max(1);
+		}
+		if let Some(quality) =
+			self.quality.filter(|_| !is_explicit(anim_args, "quality"))
+		{
+			anim.quality = quality;
+		}
+		if let Some(scale) = self.scale.filter(|_| !is_explicit(edit_args, "ratio"))
+		{
+			edit.image.ratio = scale;
+		}
+		if !is_explicit(anim_args, "dedupe") && self.dedupe.is_some() {
+			anim.dedupe = self.dedupe;
+		}
+		if let Some(gifski) = self.gifski.filter(|_| {
+			!is_explicit(anim_args, "gifski") && !is_explicit(anim_args, "fast")
+		}) {
+			anim.gifski.0 = gifski;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_anim_preset() {
+		let mut config = Config::new();
+		config
+			.with_section(Some("preset.chat"))
+			.set("fps", "5")
+			.set("gifski", "true");
+		let preset = AnimPreset::get("chat", Some(&config)).unwrap_or_default();
+		assert_eq!(
+			(Some(5), None, Some(true)),
+			(preset.fps, preset.quality, preset.gifski)
+		);
+		assert_eq!(None, AnimPreset::get("test", Some(&config)));
+		let preset = AnimPreset::get("tiny", None).unwrap_or_default();
+		let (mut anim, mut edit) =
+			(AnimSettings::default(), EditSettings::default());
+		preset.apply(None, None, &mut anim, &mut edit);
+		assert_eq!((8, 50, Some(1.)), (anim.fps, anim.quality, anim.dedupe));
+		assert_eq!(0.5, edit.image.ratio);
+		assert_eq!(false, anim.gifski.0);
+	}
+}
@@ -1,12 +1,21 @@
+use crate::apng::settings::ApngSettings;
 use crate::args::matches::ArgMatches;
 use crate::args::parser::ArgParser;
+use crate::avi::settings::AviSettings;
+use crate::exec::settings::ExecSettings;
 use crate::file::format::FileFormat;
 use crate::file::File;
+use crate::gif::settings::{Dither, GifSettings};
+use crate::image::settings::{JpgSettings, PngSettings};
+use crate::pdf::settings::{PageSize, PdfSettings};
+use crate::webp::settings::WebPSettings;
+use crate::y4m::settings::{Chroma, Y4mSettings};
+use bytesize::ByteSize;
 use std::fs;
 use std::path::PathBuf;
 
 /* Animation and frame settings */
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct AnimSettings {
 	pub fps: u32,
 	pub repeat: i32,
@@ -15,6 +24,16 @@ pub struct AnimSettings {
 	pub cut: (f32, f32),
 	pub frames: Vec<PathBuf>,
 	pub gifski: (bool, bool),
+	pub dedupe: Option<f64>,
+	pub max_size: Option<u64>,
+	pub preset: Option<String>,
+	pub gif: GifSettings,
+	pub apng: ApngSettings,
+	pub webp: WebPSettings,
+	pub y4m: Y4mSettings,
+	pub avi: AviSettings,
+	pub pdf: PdfSettings,
+	pub exec: ExecSettings,
 }
 
 /* Default initialization values for AnimSettings */
@@ -28,6 +47,16 @@ impl Default for AnimSettings {
 			cut: (0., 0.),
 			frames: Vec::new(),
 			gifski: (false, false),
+			dedupe: None,
+			max_size: None,
+			preset: None,
+			gif: GifSettings::default(),
+			apng: ApngSettings::default(),
+			webp: WebPSettings::default(),
+			y4m: Y4mSettings::default(),
+			avi: AviSettings::default(),
+			pdf: PdfSettings::default(),
+			exec: ExecSettings::default(),
 		}
 	}
 }
@@ -43,8 +72,19 @@ impl AnimSettings {
 	 * @param  cut
 	 * @param  frames
 	 * @param  gifski
+	 * @param  dedupe (Option)
+	 * @param  max_size (Option)
+	 * @param  preset (Option)
+	 * @param  gif
+	 * @param  apng
+	 * @param  webp
+	 * @param  y4m
+	 * @param  avi
+	 * @param  pdf
+	 * @param  exec
 	 * @return AnimSettings
 	 */
+	#[allow(clippy::too_many_arguments)]
 	pub fn new(
 		fps: u32,
 		repeat: i32,
@@ -53,6 +93,16 @@ impl AnimSettings {
 		cut: (f32, f32),
 		frames: Vec<PathBuf>,
 		gifski: (bool, bool),
+		dedupe: Option<f64>,
+		max_size: Option<u64>,
+		preset: Option<String>,
+		gif: GifSettings,
+		apng: ApngSettings,
+		webp: WebPSettings,
+		y4m: Y4mSettings,
+		avi: AviSettings,
+		pdf: PdfSettings,
+		exec: ExecSettings,
 	) -> Self {
 		Self {
 			fps,
@@ -62,6 +112,16 @@ impl AnimSettings {
 			cut,
 			frames,
 			gifski,
+			dedupe,
+			max_size,
+			preset,
+			gif,
+			apng,
+			webp,
+			y4m,
+			avi,
+			pdf,
+			exec,
 		}
 	}
 
@@ -73,17 +133,30 @@ impl AnimSettings {
 	 * @return AnimSettings
 	 */
 	pub fn from_args(matches: &ArgMatches<'_>, file_format: &FileFormat) -> Self {
-		let format = &file_format.to_string().to_lowercase();
 		Self::from_parser(ArgParser::from_subcommand(
 			matches,
-			if matches.is_present("make") {
-				"make"
-			} else {
-				format
-			},
+			&Self::get_section(matches, file_format),
 		))
 	}
 
+	/**
+	 * Get the name of the subcommand that contains the animation arguments.
+	 *
+	 * @param  matches
+	 * @param  file_format
+	 * @return String
+	 */
+	pub fn get_section(
+		matches: &ArgMatches<'_>,
+		file_format: &FileFormat,
+	) -> String {
+		if matches.is_present("make") {
+			String::from("make")
+		} else {
+			file_format.to_string().to_lowercase()
+		}
+	}
+
 	/**
 	 * Create a AnimSettings object from an argument parser.
 	 *
@@ -109,11 +182,59 @@ impl AnimSettings {
 					matches.is_present("gifski") || matches.is_present("fast"),
 					matches.is_present("fast"),
 				),
+				Self::get_dedupe_threshold(matches),
+				matches
+					.value_of("max-size")
+					.and_then(|size| size.parse::<ByteSize>().ok())
+					.map(|size| size.as_u64()),
+				matches.value_of("preset").map(String::from),
+				GifSettings::new(
+					parser.parse("colors", Self::default().gif.colors),
+					Dither::parse(matches.value_of("dither").unwrap_or_default()),
+					matches.is_present("global-palette"),
+					parser.parse(
+						"alpha-threshold",
+						Self::default().gif.alpha_threshold,
+					),
+				),
+				ApngSettings::new(
+					PngSettings::from_parser(ArgParser::new(Some(matches.clone()))),
+					matches.is_present("indexed"),
+				),
+				WebPSettings::new(matches.is_present("lossless")),
+				Y4mSettings::new(Chroma::parse(
+					matches.value_of("chroma").unwrap_or_default(),
+				)),
+				AviSettings::new(JpgSettings::from_parser(ArgParser::new(Some(
+					matches.clone(),
+				)))),
+				PdfSettings::new(
+					PageSize::parse(
+						matches.value_of("page-size").unwrap_or_default(),
+					),
+					matches.is_present("jpeg"),
+				),
+				ExecSettings::new(matches.value_of("encoder-cmd").map(String::from)),
 			),
 			None => Self::default(),
 		}
 	}
 
+	/**
+	 * Get the threshold for dropping duplicate frames.
+	 *
+	 * @param  args
+	 * @return f64 (Option)
+	 */
+	fn get_dedupe_threshold(args: &ArgMatches<'_>) -> Option<f64> {
+		match args.value_of("dedupe") {
+			Some("false") => None,
+			Some(threshold) => Some(threshold.parse().unwrap_or_default()),
+			None if args.is_present("dedupe") => Some(0.),
+			None => None,
+		}
+	}
+
 	/**
 	 * Get the frame files from parsed arguments.
 	 *
@@ -169,6 +290,7 @@ impl AnimSettings {
 pub struct SplitSettings {
 	pub file: PathBuf,
 	pub dir: PathBuf,
+	pub markers: bool,
 }
 
 /* Default initialization values for SplitSettings */
@@ -177,6 +299,7 @@ impl Default for SplitSettings {
 		Self {
 			file: PathBuf::new(),
 			dir: PathBuf::new(),
+			markers: false,
 		}
 	}
 }
@@ -187,10 +310,11 @@ impl SplitSettings {
 	 *
 	 * @param  file
 	 * @param  dir
+	 * @param  markers
 	 * @return SplitSettings
 	 */
-	pub fn new(file: PathBuf, dir: PathBuf) -> Self {
-		Self { file, dir }
+	pub fn new(file: PathBuf, dir: PathBuf, markers: bool) -> Self {
+		Self { file, dir, markers }
 	}
 
 	/**
@@ -224,7 +348,7 @@ impl SplitSettings {
 							.unwrap_or_default(),
 					)),
 				};
-				Self::new(file, dir)
+				Self::new(file, dir, matches.is_present("markers"))
 			}
 			None => Self::default(),
 		}
@@ -252,6 +376,28 @@ mod tests {
 					.takes_value(true),
 			)
 			.arg(Arg::with_name("cut-end").long("cut-end").takes_value(true))
+			.arg(
+				Arg::with_name("dedupe")
+					.long("dedupe")
+					.min_values(0)
+					.require_equals(true),
+			)
+			.arg(
+				Arg::with_name("max-size")
+					.long("max-size")
+					.takes_value(true),
+			)
+			.arg(Arg::with_name("preset").long("preset").takes_value(true))
+			.arg(Arg::with_name("colors").long("colors").takes_value(true))
+			.arg(Arg::with_name("dither").long("dither").takes_value(true))
+			.arg(Arg::with_name("global-palette").long("global-palette"))
+			.arg(Arg::with_name("filter").long("filter").takes_value(true))
+			.arg(Arg::with_name("indexed").long("indexed"))
+			.arg(
+				Arg::with_name("alpha-threshold")
+					.long("alpha-threshold")
+					.takes_value(true),
+			)
 			.get_matches_from(vec![
 				"test",
 				"--fps",
@@ -268,6 +414,21 @@ mod tests {
 				"0.9",
 				"--cut-end",
 				"0.8",
+				"--dedupe=0.5",
+				"--max-size",
+				"8MB",
+				"--preset",
+				"tiny",
+				"--colors",
+				"64",
+				"--dither",
+				"bayer",
+				"--global-palette",
+				"--alpha-threshold",
+				"1",
+				"--filter",
+				"paeth",
+				"--indexed",
 			]);
 		let anim_settings = AnimSettings::from_parser(ArgParser::from_args(&args));
 		assert_eq!(15, anim_settings.fps);
@@ -277,6 +438,20 @@ mod tests {
 		assert_eq!(true, anim_settings.gifski.1);
 		assert_eq!(1.1, anim_settings.speed);
 		assert_eq!((900., 800.), anim_settings.cut);
+		assert_eq!(Some(0.5), anim_settings.dedupe);
+		assert_eq!(Some(8_000_000), anim_settings.max_size);
+		assert_eq!(Some(String::from("tiny")), anim_settings.preset);
+		assert_eq!(
+			(64, Dither::Bayer, true, 1),
+			(
+				anim_settings.gif.colors,
+				anim_settings.gif.dither,
+				anim_settings.gif.global_palette,
+				anim_settings.gif.alpha_threshold
+			)
+		);
+		assert_eq!(true, anim_settings.apng.indexed);
+		assert_eq!(png::FilterType::Paeth, anim_settings.apng.get_filter());
 		let anim_settings = AnimSettings::from_parser(ArgParser::new(None));
 		assert_eq!(-1, anim_settings.repeat);
 		assert_eq!(75, anim_settings.quality);
@@ -284,14 +459,21 @@ mod tests {
 		assert_eq!(false, anim_settings.gifski.1);
 		assert_eq!(1.0, anim_settings.speed);
 		assert_eq!((0., 0.), anim_settings.cut);
+		assert_eq!(None, anim_settings.dedupe);
+		assert_eq!(None, anim_settings.max_size);
+		assert_eq!(256, anim_settings.gif.colors);
+		assert_eq!(128, anim_settings.gif.alpha_threshold);
+		assert_eq!(false, anim_settings.apng.indexed);
 	}
 	#[test]
 	fn test_split_settings() {
 		let args = App::new("test")
 			.arg(Arg::with_name("file").required(true))
-			.get_matches_from(vec!["test", "x"]);
+			.arg(Arg::with_name("markers").long("markers"))
+			.get_matches_from(vec!["test", "x", "--markers"]);
 		let split_settings = SplitSettings::from_parser(ArgParser::from_args(&args));
 		assert_eq!(PathBuf::from("x"), split_settings.file);
 		assert_eq!(Some(OsStr::new("x_frames")), split_settings.dir.file_name());
+		assert!(split_settings.markers);
 	}
 }
@@ -1,16 +1,24 @@
+pub mod settings;
+
+use crate::anim::delta::FrameDelta;
 use crate::anim::settings::AnimSettings;
 use crate::app::AppResult;
 use crate::image::geometry::Geometry;
 use crate::image::Image;
 use crate::util::state::InputState;
 use image::ExtendedColorType;
-use png::{BitDepth, ColorType, Encoder, FilterType};
+use png::{BitDepth, BlendOp, ColorType, DisposeOp, Encoder};
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::io::{self, Write};
 
+/* Maximum number of colors in an indexed palette */
+const MAX_PALETTE_COLORS: usize = 256;
+
 /* APNG encoder and settings */
 pub struct ApngEncoder<'a, Output: Write> {
 	encoder: Encoder<'a, Output>,
+	geometry: Geometry,
 	settings: &'a AnimSettings,
 }
 
@@ -37,8 +45,13 @@ impl<'a, Output: Write> ApngEncoder<'a, Output> {
 		)?;
 		encoder.set_color(ColorType::Rgba);
 		encoder.set_depth(BitDepth::Eight);
-		encoder.set_filter(FilterType::NoFilter);
-		Ok(Self { encoder, settings })
+		encoder.set_compression(settings.apng.get_compression());
+		encoder.set_filter(settings.apng.get_filter());
+		Ok(Self {
+			encoder,
+			geometry,
+			settings,
+		})
 	}
 
 	/**
@@ -49,12 +62,34 @@ impl<'a, Output: Write> ApngEncoder<'a, Output> {
 	 * @return Result
 	 */
 	pub fn save(
-		self,
+		mut self,
 		images: Vec<Image>,
 		input_state: Option<&'static InputState>,
 	) -> AppResult<()> {
+		let palette = if self.settings.apng.indexed {
+			Self::get_palette(&images)
+		} else {
+			None
+		};
+		if let Some(palette) = &palette {
+			debug!("Using an indexed palette of {} colors", palette.len());
+			let mut colors = vec![[0; 4]; palette.len()];
+			for (color, index) in palette {
+				colors[*index as usize] = *color;
+			}
+			self.encoder.set_color(ColorType::Indexed);
+			self.encoder.set_palette(
+				colors
+					.iter()
+					.flat_map(|color| color[..3].to_vec())
+					.collect::<Vec<u8>>(),
+			);
+			self.encoder
+				.set_trns(colors.iter().map(|color| color[3]).collect::<Vec<u8>>());
+		}
 		let mut writer = self.encoder.write_header()?;
-		writer.set_frame_delay(1, self.settings.fps.try_into().unwrap_or(1))?;
+		let mut previous: Option<Vec<u8>> = None;
+		let fps = self.settings.fps.try_into().unwrap_or(1);
 		for (i, image) in images.iter().enumerate() {
 			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
 			info!("Saving... ({:.1}%)\r", percentage);
@@ -72,17 +107,125 @@ impl<'a, Output: Write> ApngEncoder<'a, Output> {
 					panic!("Failed to write the frames")
 				}
 			}
-			writer.write_image_data(&image.get_data(ExtendedColorType::Rgba8))?;
+			match image.delay {
+				Some(delay) => writer
+					.set_frame_delay(delay.try_into().unwrap_or(u16::MAX), 1000)?,
+				None => writer.set_frame_delay(1, fps)?,
+			}
+			let data = image.get_data(ExtendedColorType::Rgba8);
+			let (area, blend_op, pixels) = match previous
+				.as_ref()
+				.filter(|previous| previous.len() == data.len())
+			{
+				Some(previous) => {
+					let delta =
+						FrameDelta::new(previous, &data, self.geometry.width);
+					if delta
+						.pixels
+						.iter()
+						.flatten()
+						.all(|pixel| pixel[3] == u8::MAX)
+					{
+						let pixels = delta
+							.pixels
+							.iter()
+							.flat_map(|pixel| pixel.unwrap_or_default())
+							.collect();
+						(delta.area, BlendOp::Over, pixels)
+					} else {
+						let pixels =
+							Self::get_area_data(&data, self.geometry, delta.area);
+						(delta.area, BlendOp::Source, pixels)
+					}
+				}
+				None => (
+					Geometry::new(0, 0, self.geometry.width, self.geometry.height),
+					BlendOp::Source,
+					data.clone(),
+				),
+			};
+			writer.reset_frame_position()?;
+			writer.set_frame_dimension(area.width, area.height)?;
+			writer.set_frame_position(
+				area.x.try_into().unwrap_or_default(),
+				area.y.try_into().unwrap_or_default(),
+			)?;
+			writer.set_blend_op(blend_op)?;
+			writer.set_dispose_op(DisposeOp::None)?;
+			match &palette {
+				Some(palette) => writer.write_image_data(
+					&pixels
+						.chunks(4)
+						.map(|pixel| {
+							pixel
+								.try_into()
+								.ok()
+								.and_then(|color| palette.get::<[u8; 4]>(&color))
+								.copied()
+								.unwrap_or_default()
+						})
+						.collect::<Vec<u8>>(),
+				)?,
+				None => writer.write_image_data(&pixels)?,
+			}
+			previous = Some(data);
 		}
 		info!("\n");
 		Ok(())
 	}
+
+	/**
+	 * Get the RGBA data of the given area.
+	 *
+	 * @param  data
+	 * @param  geometry
+	 * @param  area
+	 * @return Vector of u8
+	 */
+	fn get_area_data(data: &[u8], geometry: Geometry, area: Geometry) -> Vec<u8> {
+		let row_len = (geometry.width * 4) as usize;
+		data.chunks(row_len)
+			.skip(area.y as usize)
+			.take(area.height as usize)
+			.flat_map(|row| {
+				row[area.x as usize * 4..(area.x as usize + area.width as usize) * 4]
+					.to_vec()
+			})
+			.collect()
+	}
+
+	/**
+	 * Get the palette indices of colors if they fit into a palette.
+	 *
+	 * @param  images
+	 * @return HashMap (Option)
+	 */
+	fn get_palette(images: &[Image]) -> Option<HashMap<[u8; 4], u8>> {
+		let mut palette = HashMap::new();
+		palette.insert([0; 4], 0);
+		for image in images {
+			for pixel in image.get_data(ExtendedColorType::Rgba8).chunks(4) {
+				if let Ok(color) = pixel.try_into() {
+					let len = palette.len();
+					palette.entry(color).or_insert(len as u8);
+					if palette.len() > MAX_PALETTE_COLORS {
+						debug!("Too many colors for an indexed palette");
+						return None;
+					}
+				}
+			}
+		}
+		Some(palette)
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::apng::settings::ApngSettings;
+	use crate::app::AppError;
 	use image::Bgra;
+	use pretty_assertions::assert_eq;
 	#[test]
 	fn test_apng_encoder() -> AppResult<()> {
 		let geometry = Geometry::new(0, 0, 1, 2);
@@ -101,6 +244,48 @@ mod tests {
 		.save(images, None)?;
 		output.truncate(6);
 		assert_eq!(vec![0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a], output);
+		let geometry = Geometry::new(0, 0, 4, 4);
+		let mut data = vec![Bgra::from([0, 0, 0, 255]); 16];
+		let mut images = vec![Image::new(data.clone(), true, geometry)];
+		data[6] = Bgra::from([255, 255, 255, 255]);
+		images.push(Image::new(data.clone(), true, geometry));
+		data[6] = Bgra::from([0, 0, 0, 0]);
+		images.push(Image::new(data, true, geometry));
+		let settings = AnimSettings {
+			apng: ApngSettings {
+				indexed: true,
+				..ApngSettings::default()
+			},
+			..AnimSettings::default()
+		};
+		let mut output = Vec::new();
+		ApngEncoder::new(3, geometry, &mut output, &settings)?.save(images, None)?;
+		let decode_error =
+			|e: png::DecodingError| AppError::FrameError(e.to_string());
+		let mut reader = png::Decoder::new(output.as_slice())
+			.read_info()
+			.map_err(decode_error)?;
+		assert_eq!(ColorType::Indexed, reader.info().color_type);
+		let mut buffer = vec![0; reader.output_buffer_size()];
+		let mut frames = Vec::new();
+		for _ in 0..3 {
+			reader.next_frame(&mut buffer).map_err(decode_error)?;
+			let control = reader.info().frame_control.unwrap_or_default();
+			frames.push((
+				control.x_offset,
+				control.y_offset,
+				control.width,
+				control.blend_op,
+			));
+		}
+		assert_eq!(
+			vec![
+				(0, 0, 4, BlendOp::Source),
+				(2, 1, 1, BlendOp::Over),
+				(2, 1, 1, BlendOp::Source),
+			],
+			frames
+		);
 		Ok(())
 	}
 }
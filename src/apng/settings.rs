@@ -0,0 +1,86 @@
+use crate::image::settings::PngSettings;
+use image::codecs::png::{CompressionType, FilterType as ImageFilterType};
+use png::{Compression, FilterType};
+
+/* APNG encoding settings */
+#[derive(Clone, Copy, Debug)]
+pub struct ApngSettings {
+	pub png: PngSettings,
+	pub indexed: bool,
+}
+
+/* Default initialization values for ApngSettings */
+impl Default for ApngSettings {
+	fn default() -> Self {
+		Self {
+			png: PngSettings::new(
+				CompressionType::Default,
+				ImageFilterType::NoFilter,
+			),
+			indexed: false,
+		}
+	}
+}
+
+impl ApngSettings {
+	/**
+	 * Create a new ApngSettings object.
+	 *
+	 * @param  png
+	 * @param  indexed
+	 * @return ApngSettings
+	 */
+	pub fn new(png: PngSettings, indexed: bool) -> Self {
+		Self { png, indexed }
+	}
+
+	/**
+	 * Get the compression level for the PNG encoder.
+	 *
+	 * @return Compression
+	 */
+	pub fn get_compression(&self) -> Compression {
+		match self.png.compression {
+			CompressionType::Default => Compression::Default,
+			CompressionType::Best => Compression::Best,
+			CompressionType::Huffman => Compression::Huffman,
+			CompressionType::Rle => Compression::Rle,
+			_ => Compression::Fast,
+		}
+	}
+
+	/**
+	 * Get the filter algorithm for the PNG encoder.
+	 *
+	 * @return FilterType
+	 */
+	pub fn get_filter(&self) -> FilterType {
+		match self.png.filter {
+			ImageFilterType::NoFilter => FilterType::NoFilter,
+			ImageFilterType::Up => FilterType::Up,
+			ImageFilterType::Avg => FilterType::Avg,
+			ImageFilterType::Paeth => FilterType::Paeth,
+			_ => FilterType::Sub,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_apng_settings() {
+		let settings = ApngSettings::new(
+			PngSettings::new(CompressionType::Best, ImageFilterType::Paeth),
+			true,
+		);
+		assert!(matches!(settings.get_compression(), Compression::Best));
+		assert_eq!(FilterType::Paeth, settings.get_filter());
+		assert_eq!(FilterType::NoFilter, ApngSettings::default().get_filter());
+		assert!(matches!(
+			ApngSettings::default().get_compression(),
+			Compression::Default
+		));
+	}
+}
@@ -1,18 +1,35 @@
 use crate::anim::decoder::AnimDecoder;
-use crate::anim::Frames;
+use crate::anim::dedupe::FrameDeduper;
+use crate::anim::fit::{FitParams, SizeFitter};
+use crate::anim::marker::Markers;
+use crate::anim::settings::AnimSettings;
+use crate::anim::{AnimFormat, Frames};
 use crate::apng::ApngEncoder;
+use crate::args::parser::ArgParser;
 use crate::args::Args;
+use crate::avi::AviEncoder;
+use crate::exec::ExecEncoder;
 use crate::file::format::FileFormat;
 use crate::file::File as FileUtil;
 use crate::gif::encoder::{Encoder, EncoderConfig};
 #[cfg(feature = "ski")]
 use crate::gif::ski::GifskiEncoder;
 use crate::gif::GifEncoder;
+use crate::image::ico::IcoEncoder;
 use crate::image::Image;
-use crate::record::Recorder;
+use crate::pdf::PdfEncoder;
+use crate::record::events::EventLog;
+use crate::record::overlay::InputOverlay;
+use crate::record::{RecordResult, Recorder};
 use crate::settings::AppSettings;
+use crate::tiff::TiffEncoder;
+use crate::tty::cast::{AsciiCast, CastRenderer};
+use crate::tty::TtyRecorder;
+use crate::util::state::InputState;
 use crate::view::ImageViewer;
+use crate::webp::{WebPDecoder, WebPEncoder};
 use crate::window::Capture;
+use crate::y4m::Y4mEncoder;
 use bytesize::ByteSize;
 use image::bmp::BmpEncoder;
 use image::codecs::png::PngDecoder;
@@ -21,13 +38,11 @@ use image::error::{
 };
 use image::farbfeld::FarbfeldEncoder;
 use image::gif::GifDecoder;
-use image::ico::IcoEncoder;
 use image::io::Reader;
 use image::jpeg::JpegEncoder;
 use image::png::PngEncoder;
 use image::pnm::{PnmEncoder, PnmSubtype};
 use image::tga::TgaEncoder;
-use image::tiff::TiffEncoder;
 use image::{
 	AnimationDecoder, ColorType, ExtendedColorType, ImageEncoder, ImageFormat,
 };
@@ -36,7 +51,10 @@ use std::fmt::Debug;
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 use std::thread;
+use std::time::{Duration, Instant};
 use thiserror::Error as ThisError;
 
 /* Custom error implementation */
@@ -52,6 +70,12 @@ pub enum AppError {
 	GifEncoding(#[from] gif::EncodingError),
 	#[error("PNG encoding error: `{0}`")]
 	PngEncoding(#[from] png::EncodingError),
+	#[error("WebP encoding error: `{0}`")]
+	WebPEncoding(String),
+	#[error("Y4M encoding error: `{0}`")]
+	Y4mEncoding(#[from] y4m::Error),
+	#[error("TIFF encoding error: `{0}`")]
+	TiffEncoding(#[from] tiff::TiffError),
 	#[cfg(feature = "ski")]
 	#[error("gifski error: `{0}`")]
 	Gifski(#[from] gifski::Error),
@@ -73,6 +97,7 @@ pub type AppResult<T> = std::result::Result<T, AppError>;
 #[derive(Clone, Copy, Debug)]
 pub struct App<'a, Window> {
 	window: Option<Window>,
+	windows: &'a [Window],
 	settings: &'a AppSettings<'a>,
 }
 
@@ -88,7 +113,22 @@ where
 	 * @return App
 	 */
 	pub fn new(window: Option<Window>, settings: &'a AppSettings<'a>) -> Self {
-		Self { window, settings }
+		Self {
+			window,
+			windows: &[],
+			settings,
+		}
+	}
+
+	/**
+	 * Set the windows to record simultaneously.
+	 *
+	 * @param  windows
+	 * @return App
+	 */
+	pub fn with_windows(mut self, windows: &'a [Window]) -> Self {
+		self.windows = windows;
+		self
 	}
 
 	/**
@@ -100,6 +140,35 @@ where
 		trace!("Window: {:?}", self.window);
 		debug!("{:?}", self.settings.save.file);
 		debug!("Command: {:?}", self.settings.record.get_command());
+		if let Some(matches) =
+			ArgParser::from_subcommand(self.settings.args, "make").args
+		{
+			let format = &self.settings.save.file.format;
+			match matches.value_of("compression") {
+				Some(compression) if !format.supports_compression(compression) => {
+					return Err(AppError::FrameError(format!(
+						"Compression is not supported by {}: {}",
+						format.as_extension().to_uppercase(),
+						compression
+					)));
+				}
+				_ => {}
+			}
+		}
+		if self.settings.record.time.segment.is_some()
+			&& self.settings.args.is_present("record")
+			&& self.settings.save.file.path.to_str() == Some("-")
+		{
+			warn!("Segments cannot be written to stdout, ignoring --segment.");
+		}
+		if self.settings.save.file.format == FileFormat::Y4m
+			&& self.settings.args.is_present("record")
+			&& (self.settings.record.command.is_some()
+				|| self.settings.tty.enabled
+				|| self.settings.steps.is_enabled())
+		{
+			info!("The Y4M stream will be written after the recording is finished.");
+		}
 		if let Some(misc_args) = self.settings.args.subcommand_matches("misc") {
 			if let Some(shell) = misc_args.value_of("gen-completions") {
 				Args::gen_completions(shell, &mut io::stdout());
@@ -118,30 +187,80 @@ where
 		} else if self.settings.args.is_present("view") {
 			debug!("Viewing the image... ({:?})", self.settings.view.file);
 			self.view_image()?;
+		} else if self.settings.timelapse.is_enabled() {
+			self.capture_timelapse()?;
+		} else if self.settings.multi.is_enabled()
+			&& self.settings.args.is_present("record")
+		{
+			self.record_windows()?;
+		} else if self.settings.record.time.segment.is_some()
+			&& self.settings.args.is_present("record")
+			&& self.settings.save.file.path.to_str() != Some("-")
+		{
+			self.record_segments()?;
+		} else if self.settings.save.file.format == FileFormat::Y4m
+			&& self.settings.args.is_present("record")
+			&& self.settings.record.command.is_none()
+			&& !self.settings.tty.enabled
+			&& !self.settings.steps.is_enabled()
+		{
+			if self.settings.save.file.path.to_str() == Some("-") {
+				self.record_stream(io::stdout())?;
+			} else {
+				self.record_stream(File::create(&self.settings.save.file.path)?)?;
+				info!(
+					"Y4M saved to: {:?} ({})",
+					self.settings.save.file.path,
+					ByteSize(fs::metadata(&self.settings.save.file.path)?.len())
+				);
+			}
+		} else if let (FileFormat::Ico, Some(dir)) = (
+			&self.settings.save.file.format,
+			&self.settings.ico.favicon_set,
+		) {
+			self.save_favicon_set(self.get_app_output()?.0, dir)?;
+			info!("Favicon set saved to: {:?}", dir);
+		} else if self.settings.save.file.format == FileFormat::Exec {
+			self.save_output(self.get_app_output()?, io::sink())?;
 		} else if self.settings.save.file.path.to_str() == Some("-") {
 			self.save_output(self.get_app_output()?, io::stdout())?;
 		} else {
-			self.save_output(
-				self.get_app_output()?,
-				File::create(&self.settings.save.file.path)?,
-			)?;
-			info!(
-				"{} saved to: {:?} ({})",
-				self.settings.save.file.format.as_extension().to_uppercase(),
-				self.settings.save.file.path,
-				ByteSize(fs::metadata(&self.settings.save.file.path)?.len())
-			);
+			self.save_file(self.get_app_output()?, &self.settings.save.file.path)?;
 		}
 		Ok(())
 	}
 
+	/**
+	 * Save the application output to the file along with its markers.
+	 *
+	 * @param  app_output
+	 * @param  path
+	 * @return Result
+	 */
+	fn save_file(self, app_output: AppOutput, path: &Path) -> AppResult<()> {
+		let markers = self.save_output(app_output, File::create(path)?)?;
+		info!(
+			"{} saved to: {:?} ({})",
+			self.settings.save.file.format.as_extension().to_uppercase(),
+			path,
+			ByteSize(fs::metadata(path)?.len())
+		);
+		markers.write(path)?;
+		Ok(())
+	}
+
 	/**
 	 * Get the application output.
 	 *
 	 * @return AppOutput (Result)
 	 */
 	fn get_app_output(self) -> AppResult<AppOutput> {
-		let output = if self.settings.save.file.format.is_animation() {
+		let format = &self.settings.save.file.format;
+		let output = if format.is_animation()
+			|| (format.is_multi_page()
+				&& (self.settings.args.is_present("record")
+					|| self.settings.args.is_present("make")))
+		{
 			(None, Some(self.get_frames()?))
 		} else {
 			(Some(self.get_image()?), None)
@@ -173,13 +292,15 @@ where
 	 * @return Frames (Result)
 	 */
 	fn get_frames(self) -> AppResult<Frames> {
-		if self.settings.args.is_present("edit") {
+		let frames = if self.settings.args.is_present("edit") {
 			info!("Reading frames from {:?}...", self.settings.edit.path);
 			self.edit_anim(
 				File::open(&self.settings.edit.path)?,
 				&self.settings.edit.path,
-			)
-		} else if self.settings.args.is_present("make") {
+			)?
+		} else if self.settings.args.is_present("make")
+			&& self.settings.cast.file.is_none()
+		{
 			info!(
 				"Making an animation from {} frames...",
 				self.settings.anim.frames.len()
@@ -191,9 +312,51 @@ where
 				images.push(self.edit_image(path)?);
 			}
 			debug!("\n");
-			Ok((images, self.settings.anim.fps))
+			(images, self.settings.anim.fps)
 		} else {
-			Ok((self.record()?, self.settings.anim.fps))
+			let images = if let Some(path) = &self.settings.cast.file {
+				self.render_cast(path)?
+			} else if self.settings.tty.enabled {
+				self.record_tty()?
+			} else if self.settings.steps.is_enabled() {
+				self.record_steps()?
+			} else {
+				self.record()?
+			};
+			(self.scale_images(images), self.settings.anim.fps)
+		};
+		Ok(self.dedupe_frames(frames))
+	}
+
+	/**
+	 * Remove the duplicate frames if enabled.
+	 *
+	 * @param  frames
+	 * @return Frames
+	 */
+	fn dedupe_frames(self, frames: Frames) -> Frames {
+		match self.settings.anim.dedupe {
+			Some(threshold) => FrameDeduper::new(threshold).dedupe(frames),
+			None => frames,
+		}
+	}
+
+	/**
+	 * Resize the recorded images with the image ratio.
+	 *
+	 * @param  images
+	 * @return Vector of Image
+	 */
+	fn scale_images(self, images: Vec<Image>) -> Vec<Image> {
+		let ratio = self.settings.edit.image.ratio;
+		if ratio > 0. && (ratio - 1.).abs() > f32::EPSILON {
+			info!("Resizing the frames... ({}x)", ratio);
+			images
+				.iter()
+				.map(|image| image.resize(ratio, self.settings.edit.image.filter))
+				.collect()
+		} else {
+			images
 		}
 	}
 
@@ -232,6 +395,231 @@ where
 		.ok_or_else(|| AppError::WsError(String::from("Failed to get image")))
 	}
 
+	/**
+	 * Capture images periodically and save them as separate files.
+	 *
+	 * @return Result
+	 */
+	fn capture_timelapse(self) -> AppResult<()> {
+		let window = self.window.ok_or_else(|| {
+			AppError::WsError(String::from("Failed to get the window"))
+		})?;
+		let every = Duration::from_secs_f64(
+			self.settings.timelapse.every.unwrap_or_default(),
+		);
+		let count = self.settings.timelapse.count.unwrap_or(usize::MAX);
+		let capturing = Arc::new(AtomicBool::new(true));
+		let capture_state = capturing.clone();
+		ctrlc::set_handler(move || {
+			capture_state.store(false, Ordering::SeqCst);
+		})?;
+		window.show_countdown();
+		info!(
+			"Capturing an image every {:.1} seconds...",
+			every.as_secs_f64()
+		);
+		let mut images = Vec::new();
+		let mut index = 0;
+		while capturing.load(Ordering::SeqCst) && index < count {
+			let capture_time = Instant::now();
+			let image = window.get_image().ok_or_else(|| {
+				AppError::WsError(String::from("Failed to get image"))
+			})?;
+			index += 1;
+			let path = self.settings.save.get_sequence_path(index);
+			self.save_output((Some(image.clone()), None), File::create(&path)?)?;
+			info!("Image saved to: {:?} [{}]", path, index);
+			if self.settings.timelapse.format.is_some() {
+				images.push(image);
+			}
+			while index < count
+				&& capture_time.elapsed() < every
+				&& capturing.load(Ordering::SeqCst)
+			{
+				if let Some(state) = self.settings.input_state {
+					if state.check_cancel_keys() {
+						warn!("User interrupt detected.");
+						capturing.store(false, Ordering::SeqCst);
+					}
+				}
+				thread::sleep(Duration::from_millis(
+					self.settings.record.time.interval,
+				));
+			}
+		}
+		window.release();
+		if let Some(format) = self.settings.timelapse.format {
+			let path = self
+				.settings
+				.save
+				.file
+				.path
+				.with_extension(format.to_string().to_lowercase());
+			info!("Making an animation from {} frames...", images.len());
+			let frames = Some((images, self.settings.anim.fps));
+			match format {
+				AnimFormat::Apng => self.save_apng(
+					frames,
+					File::create(&path)?,
+					&self.settings.anim,
+				)?,
+				AnimFormat::WebP => self.save_webp(
+					frames,
+					File::create(&path)?,
+					&self.settings.anim,
+				)?,
+				AnimFormat::Y4m => {
+					self.save_y4m(frames, File::create(&path)?, &self.settings.anim)?
+				}
+				AnimFormat::Avi => {
+					self.save_avi(frames, File::create(&path)?, &self.settings.anim)?
+				}
+				AnimFormat::Tiff => self.save_tiff(
+					frames.map(|(images, _)| images),
+					File::create(&path)?,
+				)?,
+				AnimFormat::Pdf => {
+					self.save_pdf(frames, File::create(&path)?, &self.settings.anim)?
+				}
+				_ => {
+					self.save_gif(frames, File::create(&path)?, &self.settings.anim)?
+				}
+			}
+			info!(
+				"{} saved to: {:?} ({})",
+				format.to_string().to_uppercase(),
+				path,
+				ByteSize(fs::metadata(&path)?.len())
+			);
+		}
+		Ok(())
+	}
+
+	/**
+	 * Record the command in a pseudo-terminal.
+	 *
+	 * @return Vector of Image (Result)
+	 */
+	fn record_tty(self) -> AppResult<Vec<Image>> {
+		let command = self.settings.record.command.ok_or_else(|| {
+			AppError::CommandError(String::from("No command specified to run"))
+		})?;
+		let recording = Arc::new(AtomicBool::new(true));
+		let rec_state = recording.clone();
+		ctrlc::set_handler(move || {
+			rec_state.store(false, Ordering::SeqCst);
+		})?;
+		Ok(TtyRecorder::new(
+			self.settings.tty,
+			self.settings.anim.fps,
+			self.settings.record.time.duration,
+		)
+		.record(command, &recording)?)
+	}
+
+	/**
+	 * Render the asciicast recording as frames.
+	 *
+	 * @param  path
+	 * @return Vector of Image (Result)
+	 */
+	fn render_cast(self, path: &Path) -> AppResult<Vec<Image>> {
+		info!("Reading the terminal recording from {:?}...", path);
+		let cast = AsciiCast::parse(io::BufReader::new(File::open(path)?))?;
+		info!(
+			"Rendering {} events [{}x{}]...",
+			cast.events.len(),
+			cast.width,
+			cast.height
+		);
+		Ok(
+			CastRenderer::new(&self.settings.cast, self.settings.anim.fps)
+				.render(&cast),
+		)
+	}
+
+	/**
+	 * Record a step on each mouse click or action key press.
+	 *
+	 * @return Vector of Image (Result)
+	 */
+	fn record_steps(self) -> AppResult<Vec<Image>> {
+		let window = self.window.ok_or_else(|| {
+			AppError::WsError(String::from("Failed to get the window"))
+		})?;
+		let input_state = self.settings.input_state.ok_or_else(|| {
+			AppError::WsError(String::from("Failed to get the input state"))
+		})?;
+		let hold = (self.settings.steps.hold.unwrap_or_default() * 1e3) as u32;
+		let recording = Arc::new(AtomicBool::new(true));
+		let rec_state = recording.clone();
+		ctrlc::set_handler(move || {
+			rec_state.store(false, Ordering::SeqCst);
+		})?;
+		window.show_countdown();
+		info!(
+			"Recording a step on each click or action key... ({} to finish)",
+			input_state.cancel_keys
+		);
+		let mut images = Vec::new();
+		let mut pressed = true;
+		let mut marker = false;
+		while recording.load(Ordering::SeqCst) && !input_state.check_cancel_keys() {
+			let action = input_state.check_step();
+			if !marker && input_state.check_marker_keys() {
+				info!("Added a marker at step {}.", images.len() + 1);
+				marker = true;
+			}
+			if action && !pressed {
+				let mut image = window.get_image().ok_or_else(|| {
+					AppError::WsError(String::from("Failed to get image"))
+				})?;
+				image.delay = Some(hold);
+				image.marker = marker;
+				marker = false;
+				images.push(image);
+				info!("Step {} recorded.", images.len());
+			}
+			pressed = action;
+			thread::sleep(Duration::from_millis(self.settings.record.time.interval));
+		}
+		window.release();
+		if let Some(dir) = self.settings.steps.dir {
+			self.save_steps(&images, Path::new(dir))?;
+		}
+		Ok(images)
+	}
+
+	/**
+	 * Save the recorded steps as PNG images with a Markdown index.
+	 *
+	 * @param  images
+	 * @param  dir
+	 * @return Result
+	 */
+	fn save_steps(self, images: &[Image], dir: &Path) -> AppResult<()> {
+		fs::create_dir_all(dir)?;
+		let mut index = String::from("# Steps\n");
+		for (i, image) in images.iter().enumerate() {
+			let file_name = format!("step_{}.png", i + 1);
+			PngEncoder::new_with_quality(
+				File::create(dir.join(&file_name))?,
+				self.settings.png.compression,
+				self.settings.png.filter,
+			)
+			.write_image(
+				&image.get_data(ExtendedColorType::Rgba8),
+				image.geometry.width,
+				image.geometry.height,
+				ColorType::Rgba8,
+			)?;
+			index += &format!("\n{}. ![Step {}]({})\n", i + 1, i + 1, file_name);
+		}
+		fs::write(dir.join("index.md"), index)?;
+		info!("{} steps saved to: {:?}", images.len(), dir);
+		Ok(())
+	}
+
 	/**
 	 * Start recording the frames.
 	 *
@@ -245,9 +633,11 @@ where
 			self.settings.anim.fps,
 			self.settings.anim.gifski.0,
 			self.settings.record,
+			self.get_overlay(),
+			self.get_event_log()?,
 		);
 		if self.settings.record.command.is_some() {
-			let record = recorder.record_async();
+			let record = recorder.record_async(self.get_input_state());
 			self.settings
 				.record
 				.get_command()
@@ -262,16 +652,258 @@ where
 				None => Vec::new(),
 			})
 		} else {
-			Ok(recorder.record_sync(
-				if self.settings.record.flag.action_keys.is_some() {
-					self.settings.input_state
-				} else {
-					None
-				},
-			)?)
+			Ok(recorder.record_sync(self.get_input_state())?)
+		}
+	}
+
+	/**
+	 * Get the input state for checking the action and marker keys.
+	 *
+	 * @return InputState (Option)
+	 */
+	fn get_input_state(self) -> Option<&'static InputState> {
+		if self.settings.record.flag.action_keys.is_some()
+			|| self.settings.record.flag.marker_keys.is_some()
+		{
+			self.settings.input_state
+		} else {
+			None
 		}
 	}
 
+	/**
+	 * Get the overlay for drawing the user input on the frames.
+	 *
+	 * @return InputOverlay (Option)
+	 */
+	fn get_overlay(self) -> Option<InputOverlay> {
+		if self.settings.record.overlay.is_enabled() {
+			Some(InputOverlay::new(
+				self.settings.record.overlay,
+				self.settings.input_state,
+				self.settings.anim.fps,
+			))
+		} else {
+			None
+		}
+	}
+
+	/**
+	 * Get the log for writing the input events of the frames.
+	 *
+	 * @return EventLog (Option)
+	 */
+	fn get_event_log(self) -> AppResult<Option<EventLog>> {
+		Ok(match self.settings.record.events {
+			Some(path) => {
+				info!("Writing the input events to {:?}", path);
+				Some(EventLog::new(Path::new(path), self.settings.input_state)?)
+			}
+			None => None,
+		})
+	}
+
+	/**
+	 * Record the frames in segments and save each of them as a separate file.
+	 *
+	 * @return Result
+	 */
+	fn record_segments(self) -> AppResult<()> {
+		if self.settings.record.command.is_some() {
+			return Err(AppError::CommandError(String::from(
+				"Recording segments is not supported while running a command",
+			)));
+		}
+		let mut recorder = Recorder::new(
+			self.window.ok_or_else(|| {
+				AppError::WsError(String::from("Failed to get the window"))
+			})?,
+			self.settings.anim.fps,
+			self.settings.anim.gifski.0,
+			self.settings.record,
+			self.get_overlay(),
+			self.get_event_log()?,
+		);
+		let segment_frames = ((self.settings.record.time.segment.unwrap_or_default()
+			* self.settings.anim.fps as f64) as usize)
+			.max(1);
+		let input_state = self.get_input_state();
+		let (sender, receiver) = mpsc::sync_channel(1);
+		let recorder_thread = thread::spawn(move || {
+			recorder.record_segments(input_state, segment_frames, |images| {
+				sender.send(images).map_err(|_| {
+					AppError::FrameError(String::from("Failed to send the segment"))
+				})
+			})
+		});
+		for (i, images) in receiver.iter().enumerate() {
+			self.save_part(images, i + 1)?;
+		}
+		recorder_thread
+			.join()
+			.expect("Failed to join the recorder thread")
+	}
+
+	/**
+	 * Record the frames and write them to the Y4M stream as they are captured.
+	 *
+	 * @param  output
+	 * @return Result
+	 */
+	fn record_stream<Output: Write>(self, output: Output) -> AppResult<()> {
+		let window = self.window.ok_or_else(|| {
+			AppError::WsError(String::from("Failed to get the window"))
+		})?;
+		let mut recorder = Recorder::new(
+			window,
+			self.settings.anim.fps,
+			self.settings.anim.gifski.0,
+			self.settings.record,
+			self.get_overlay(),
+			self.get_event_log()?,
+		);
+		let input_state = self.get_input_state();
+		let mut encoder =
+			Y4mEncoder::new(self.settings.anim.fps, output, &self.settings.anim);
+		recorder.record_segments(input_state, 1, |images| {
+			for image in self.scale_images(images) {
+				encoder.add(&image)?;
+			}
+			Ok(())
+		})?;
+		window.release();
+		encoder.finish()
+	}
+
+	/**
+	 * Record the windows simultaneously and save them separately
+	 * or composed side by side.
+	 *
+	 * @return Result
+	 */
+	fn record_windows(self) -> AppResult<()> {
+		if self.settings.save.file.path.to_str() == Some("-") {
+			return Err(AppError::FrameError(String::from(
+				"Recording multiple windows is not supported for stdout",
+			)));
+		}
+		let input_state = self.get_input_state();
+		let recording = Arc::new(AtomicBool::new(true));
+		let rec_state = recording.clone();
+		ctrlc::set_handler(move || {
+			rec_state.store(false, Ordering::SeqCst);
+		})?;
+		self.windows
+			.iter()
+			.for_each(|window| window.show_countdown());
+		let start = Instant::now();
+		let records = self
+			.windows
+			.iter()
+			.map(|window| {
+				Recorder::new(
+					*window,
+					self.settings.anim.fps,
+					self.settings.anim.gifski.0,
+					self.settings.record,
+					self.get_overlay(),
+					None,
+				)
+				.with_start(start)
+				.record_async(input_state)
+			})
+			.collect::<Vec<RecordResult<Vec<Image>>>>();
+		info!(
+			"Recording {} windows at {} FPS...",
+			records.len(),
+			self.settings.anim.fps
+		);
+		let mut cancelled = false;
+		if let Some(command) = self.settings.record.get_command() {
+			command.execute()?;
+		} else {
+			while recording.load(Ordering::SeqCst) {
+				if let Some(state) = input_state {
+					if state.check_cancel_keys() {
+						warn!("User interrupt detected.");
+						cancelled = true;
+						break;
+					} else if state.check_action() {
+						break;
+					}
+				}
+				if matches!(self.settings.record.time.duration,
+					Some(duration) if start.elapsed().as_secs_f64() >= duration)
+				{
+					break;
+				}
+				thread::sleep(Duration::from_millis(
+					self.settings.record.time.interval,
+				));
+			}
+		}
+		let recordings = records
+			.into_iter()
+			.map(|record| match record.get() {
+				Some(frames) => frames.expect("Failed to retrieve the frames."),
+				None => Vec::new(),
+			})
+			.collect::<Vec<Vec<Image>>>();
+		if let Some(window) = self.windows.first() {
+			window.release();
+		}
+		if cancelled {
+			return Ok(());
+		}
+		if self.settings.multi.compose {
+			let frame_count =
+				recordings.iter().map(Vec::len).min().unwrap_or_default();
+			let images = (0..frame_count)
+				.map(|i| {
+					Image::compose(
+						&recordings
+							.iter()
+							.map(|images| &images[i])
+							.collect::<Vec<&Image>>(),
+					)
+				})
+				.collect::<Vec<Image>>();
+			self.save_file(
+				(
+					None,
+					Some(self.dedupe_frames((images, self.settings.anim.fps))),
+				),
+				&self.settings.save.file.path,
+			)?;
+		} else {
+			for (i, images) in recordings.into_iter().enumerate() {
+				self.save_part(images, i + 1)?;
+			}
+		}
+		Ok(())
+	}
+
+	/**
+	 * Save a part of the recording to a file with the given index.
+	 *
+	 * @param  images
+	 * @param  index
+	 * @return Result
+	 */
+	fn save_part(self, images: Vec<Image>, index: usize) -> AppResult<()> {
+		let path = FileUtil::get_path_with_info(
+			&self.settings.save.file.path,
+			&index.to_string(),
+		);
+		self.save_file(
+			(
+				None,
+				Some(self.dedupe_frames((images, self.settings.anim.fps))),
+			),
+			&path,
+		)
+	}
+
 	/**
 	 * Edit and return the image.
 	 *
@@ -336,24 +968,45 @@ where
 	 */
 	fn edit_anim<Input: Read>(self, input: Input, path: &Path) -> AppResult<Frames> {
 		let format = Reader::open(path)?.with_guessed_format()?.format();
-		let frames =
-			AnimDecoder::new(self.settings.edit.get_imageops(), &self.settings.anim)
-				.update_frames(match format {
-					Some(ImageFormat::Gif) => {
-						GifDecoder::new(input)?.into_frames().collect_frames()
-					}
-					Some(ImageFormat::Png) => PngDecoder::new(input)?
-						.apng()
-						.into_frames()
-						.collect_frames(),
-					_ => Err(ImageError::Unsupported(
-						UnsupportedError::from_format_and_kind(
-							ImageFormatHint::Unknown,
-							UnsupportedErrorKind::Format(ImageFormatHint::Unknown),
-						),
-					)),
-				}?)?;
-		Ok(frames)
+		let mut frames = match format {
+			Some(ImageFormat::Gif) => {
+				GifDecoder::new(input)?.into_frames().collect_frames()
+			}
+			Some(ImageFormat::Png) => PngDecoder::new(input)?
+				.apng()
+				.into_frames()
+				.collect_frames(),
+			Some(ImageFormat::WebP) => WebPDecoder::new(input).into_frames(),
+			_ => Err(ImageError::Unsupported(
+				UnsupportedError::from_format_and_kind(
+					ImageFormatHint::Unknown,
+					UnsupportedErrorKind::Format(ImageFormatHint::Unknown),
+				),
+			)),
+		}?;
+		let mut markers = match Markers::read(path) {
+			Ok(markers) => markers,
+			Err(e)
+				if self.settings.edit.marker_range.is_none()
+					&& !self.settings.split.markers =>
+			{
+				debug!("{}", e);
+				Markers::default()
+			}
+			Err(e) => return Err(e),
+		};
+		if let Some(range) = self.settings.edit.marker_range {
+			let range = markers.get_range(range, frames.len());
+			info!(
+				"Keeping the frames between {} and {}.",
+				range.start, range.end
+			);
+			markers = markers.rebase(range.clone());
+			frames = frames.drain(range).collect();
+		}
+		AnimDecoder::new(self.settings.edit.get_imageops(), &self.settings.anim)
+			.with_markers(markers)
+			.update_frames(frames)
 	}
 
 	/**
@@ -366,6 +1019,9 @@ where
 		let (frames, fps) = self.edit_anim(input, &self.settings.split.file)?;
 		debug!("FPS: {}", fps);
 		fs::create_dir_all(&self.settings.split.dir)?;
+		if self.settings.split.markers {
+			return self.split_anim_markers((frames, fps));
+		}
 		for i in 0..frames.len() {
 			let path = FileUtil::get_path_with_extension(
 				self.settings.split.dir.join(format!("frame_{}", i,)),
@@ -380,26 +1036,94 @@ where
 	}
 
 	/**
-	 * Save the application output.
+	 * Split animation into separate animations at the chapter markers.
+	 *
+	 * @param  frames
+	 * @return Result
+	 */
+	fn split_anim_markers(self, frames: Frames) -> AppResult<()> {
+		let (mut images, fps) = frames;
+		let markers = Markers::from_images(&images);
+		if markers.is_empty() {
+			return Err(AppError::FrameError(String::from(
+				"No markers found to split the animation",
+			)));
+		}
+		let format = Reader::open(&self.settings.split.file)?
+			.with_guessed_format()?
+			.format();
+		let mut offset = 0;
+		for (i, range) in markers.get_ranges(images.len()).into_iter().enumerate() {
+			let chapter = images
+				.drain(range.start - offset..range.end - offset)
+				.collect::<Vec<Image>>();
+			offset = range.end;
+			let path = self.settings.split.dir.join(format!(
+				"chapter_{}.{}",
+				i,
+				match format {
+					Some(ImageFormat::Gif) => "gif",
+					Some(ImageFormat::WebP) => "webp",
+					_ => "png",
+				}
+			));
+			debug!("Saving to {:?}\r", path);
+			io::stdout().flush()?;
+			let frames = Some((chapter, fps));
+			match format {
+				Some(ImageFormat::Gif) => {
+					self.save_gif(frames, File::create(path)?, &self.settings.anim)?
+				}
+				Some(ImageFormat::WebP) => {
+					self.save_webp(frames, File::create(path)?, &self.settings.anim)?
+				}
+				_ => {
+					self.save_apng(frames, File::create(path)?, &self.settings.anim)?
+				}
+			}
+		}
+		debug!("\n");
+		Ok(())
+	}
+
+	/**
+	 * Save the application output and return the markers of the saved frames.
 	 *
 	 * @param   app_output
 	 * @param   output
-	 * @return  Result
+	 * @return  Markers (Result)
 	 */
 	fn save_output<Output: Write>(
 		&self,
 		app_output: AppOutput,
 		mut output: Output,
-	) -> AppResult<()> {
+	) -> AppResult<Markers> {
 		let (image, frames) = app_output;
+		let markers = frames
+			.as_ref()
+			.map(|(images, _)| Markers::from_images(images))
+			.unwrap_or_default();
 		match self.settings.save.file.format {
-			FileFormat::Gif => {
-				debug!("{:?}", self.settings.anim);
-				self.save_gif(frames, output)
-			}
-			FileFormat::Apng => {
+			FileFormat::Exec => self.save_exec(
+				frames,
+				&self.settings.save.file.path,
+				&self.settings.anim,
+			),
+			FileFormat::Gif
+			| FileFormat::Apng
+			| FileFormat::WebP
+			| FileFormat::Y4m
+			| FileFormat::Avi
+			| FileFormat::Pdf => {
 				debug!("{:?}", self.settings.anim);
-				self.save_apng(frames, output)
+				match self.settings.anim.max_size {
+					Some(max_size) => {
+						let params =
+							self.save_fitted_anim(frames, output, max_size)?;
+						return Ok(markers.step(params.step));
+					}
+					None => self.save_anim(frames, output, &self.settings.anim),
+				}
 			}
 			FileFormat::Png => self.save_image(
 				image,
@@ -423,15 +1147,12 @@ where
 				BmpEncoder::new(&mut output),
 				ExtendedColorType::Rgba8,
 			),
-			FileFormat::Ico => self.save_image(
-				image,
-				IcoEncoder::new(output),
-				ExtendedColorType::Rgba8,
-			),
-			FileFormat::Tiff => self.save_image(
-				image,
-				TiffEncoder::new(File::create(&self.settings.save.file.path)?),
-				ExtendedColorType::Rgba8,
+			FileFormat::Ico => self.save_ico(image, output),
+			FileFormat::Tiff => self.save_tiff(
+				frames
+					.map(|(images, _)| images)
+					.or_else(|| image.map(|image| vec![image])),
+				output,
 			),
 			FileFormat::Tga => self.save_image(
 				image,
@@ -454,9 +1175,85 @@ where
 				ExtendedColorType::Rgba16,
 			),
 			_ => Ok(()),
+		}?;
+		Ok(markers)
+	}
+
+	/**
+	 * Save frames as an animation in the output format.
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @param   settings
+	 * @return  Result
+	 */
+	fn save_anim<Output: Write>(
+		self,
+		frames: Option<Frames>,
+		output: Output,
+		settings: &AnimSettings,
+	) -> AppResult<()> {
+		match self.settings.save.file.format {
+			FileFormat::Apng => self.save_apng(frames, output, settings),
+			FileFormat::WebP => self.save_webp(frames, output, settings),
+			FileFormat::Y4m => self.save_y4m(frames, output, settings),
+			FileFormat::Avi => self.save_avi(frames, output, settings),
+			FileFormat::Pdf => self.save_pdf(frames, output, settings),
+			_ => self.save_gif(frames, output, settings),
 		}
 	}
 
+	/**
+	 * Save frames as an animation that fits into the given size.
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @param   max_size
+	 * @return  FitParams (Result)
+	 */
+	fn save_fitted_anim<Output: Write>(
+		self,
+		frames: Option<Frames>,
+		mut output: Output,
+		max_size: u64,
+	) -> AppResult<FitParams> {
+		let frames = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		let quality = match self.settings.save.file.format {
+			FileFormat::Gif if self.settings.anim.gifski.0 => {
+				Some(self.settings.anim.quality)
+			}
+			FileFormat::WebP if !self.settings.anim.webp.lossless => {
+				Some(self.settings.anim.quality)
+			}
+			FileFormat::Avi => Some(self.settings.anim.avi.jpg.quality),
+			FileFormat::Pdf if self.settings.anim.pdf.jpeg => {
+				Some(self.settings.anim.quality)
+			}
+			_ => None,
+		};
+		let (params, data) =
+			SizeFitter::new(max_size, quality).fit(&frames, |frames, params| {
+				let mut settings = self.settings.anim.clone();
+				settings.quality = params.quality.unwrap_or(settings.quality);
+				settings.avi.jpg.quality =
+					params.quality.unwrap_or(settings.avi.jpg.quality);
+				let mut data = Vec::new();
+				self.save_anim(Some(frames), &mut data, &settings)?;
+				Ok(data)
+			})?;
+		info!(
+			"Encoded with quality: {}, frame step: {}, scale: {:.2} ({})",
+			params.quality.unwrap_or(self.settings.anim.quality),
+			params.step,
+			params.scale,
+			ByteSize(data.len() as u64)
+		);
+		output.write_all(&data)?;
+		Ok(params)
+	}
+
 	/**
 	 * Save the image to a file.
 	 *
@@ -499,11 +1296,77 @@ where
 		Ok(())
 	}
 
+	/**
+	 * Save the image as ICO in multiple sizes.
+	 *
+	 * @param  image (Option)
+	 * @param  output
+	 * @return Result
+	 */
+	fn save_ico<Output: Write>(
+		self,
+		image: Option<Image>,
+		output: Output,
+	) -> AppResult<()> {
+		let image = image.ok_or_else(|| {
+			AppError::WsError(String::from("Failed to get the image"))
+		})?;
+		info!("Saving the image as ICO...");
+		debug!("{:?}", image);
+		debug!("{:?}", self.settings.ico);
+		IcoEncoder::new(output, self.settings.ico.clone()).encode(&image)
+	}
+
+	/**
+	 * Save the images as pages of a TIFF file.
+	 *
+	 * @param  images (Option)
+	 * @param  output
+	 * @return Result
+	 */
+	fn save_tiff<Output: Write>(
+		self,
+		images: Option<Vec<Image>>,
+		output: Output,
+	) -> AppResult<()> {
+		let images = images.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		debug!("{:?}", self.settings.tiff);
+		TiffEncoder::new(output, self.settings.tiff)
+			.save(images, self.settings.input_state)
+	}
+
+	/**
+	 * Save the icon sizes as PNG and ICO into the directory.
+	 *
+	 * @param  image (Option)
+	 * @param  dir
+	 * @return Result
+	 */
+	fn save_favicon_set(self, image: Option<Image>, dir: &Path) -> AppResult<()> {
+		let image = image.ok_or_else(|| {
+			AppError::WsError(String::from("Failed to get the image"))
+		})?;
+		info!("Saving the favicon set...");
+		fs::create_dir_all(dir)?;
+		let encoder = IcoEncoder::new(
+			File::create(dir.join("favicon.ico"))?,
+			self.settings.ico.clone(),
+		);
+		let icons = encoder.get_icons(&image)?;
+		for (size, data) in &icons {
+			fs::write(dir.join(format!("favicon-{0}x{0}.png", size)), data)?;
+		}
+		encoder.write(&icons)
+	}
+
 	/**
 	 * Save frames to a GIF file.
 	 *
 	 * @param   frames (Option)
 	 * @param   output
+	 * @param   settings
 	 * @return  Result
 	 */
 	#[cfg(feature = "ski")]
@@ -511,6 +1374,7 @@ where
 		self,
 		frames: Option<Frames>,
 		output: Output,
+		settings: &AnimSettings,
 	) -> AppResult<()> {
 		let (images, fps) = frames.ok_or_else(|| {
 			AppError::FrameError(String::from("Failed to get the frames"))
@@ -521,8 +1385,8 @@ where
 				AppError::FrameError(String::from("No frames found to save"))
 			})?
 			.geometry;
-		let config = EncoderConfig::new(fps, geometry, output, &self.settings.anim);
-		if self.settings.anim.gifski.0 {
+		let config = EncoderConfig::new(fps, geometry, output, settings);
+		if settings.gifski.0 {
 			GifskiEncoder::new(config)?.save(images, self.settings.input_state)?;
 		} else {
 			GifEncoder::new(config)?.save(images, self.settings.input_state)?;
@@ -535,6 +1399,7 @@ where
 	 *
 	 * @param   frames (Option)
 	 * @param   output
+	 * @param   settings
 	 * @return  Result
 	 */
 	#[cfg(not(feature = "ski"))]
@@ -542,6 +1407,7 @@ where
 		self,
 		frames: Option<Frames>,
 		output: Output,
+		settings: &AnimSettings,
 	) -> AppResult<()> {
 		let (images, fps) = frames.ok_or_else(|| {
 			AppError::FrameError(String::from("Failed to get the frames"))
@@ -552,13 +1418,8 @@ where
 				AppError::FrameError(String::from("No frames found to save"))
 			})?
 			.geometry;
-		GifEncoder::new(EncoderConfig::new(
-			fps,
-			geometry,
-			output,
-			&self.settings.anim,
-		))?
-		.save(images, self.settings.input_state)?;
+		GifEncoder::new(EncoderConfig::new(fps, geometry, output, settings))?
+			.save(images, self.settings.input_state)?;
 		Ok(())
 	}
 
@@ -567,12 +1428,14 @@ where
 	 *
 	 * @param   frames (Option)
 	 * @param   output
+	 * @param   settings
 	 * @return  Result
 	 */
 	fn save_apng<Output: Write>(
 		self,
 		frames: Option<Frames>,
 		output: Output,
+		settings: &AnimSettings,
 	) -> AppResult<()> {
 		let images = frames
 			.ok_or_else(|| {
@@ -589,11 +1452,138 @@ where
 			images.len().try_into().unwrap_or_default(),
 			geometry,
 			output,
-			&self.settings.anim,
+			settings,
 		)?
 		.save(images, self.settings.input_state)?;
 		Ok(())
 	}
+
+	/**
+	 * Save frames to a WebP file.
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @param   settings
+	 * @return  Result
+	 */
+	fn save_webp<Output: Write>(
+		self,
+		frames: Option<Frames>,
+		output: Output,
+		settings: &AnimSettings,
+	) -> AppResult<()> {
+		let (images, fps) = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		let geometry = images
+			.first()
+			.ok_or_else(|| {
+				AppError::FrameError(String::from("No frames found to save"))
+			})?
+			.geometry;
+		WebPEncoder::new(fps, geometry, output, settings)?
+			.save(images, self.settings.input_state)?;
+		Ok(())
+	}
+
+	/**
+	 * Save frames to a Y4M stream.
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @param   settings
+	 * @return  Result
+	 */
+	fn save_y4m<Output: Write>(
+		self,
+		frames: Option<Frames>,
+		output: Output,
+		settings: &AnimSettings,
+	) -> AppResult<()> {
+		let (images, fps) = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		Y4mEncoder::new(fps, output, settings)
+			.save(images, self.settings.input_state)?;
+		Ok(())
+	}
+
+	/**
+	 * Save frames to an AVI file.
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @param   settings
+	 * @return  Result
+	 */
+	fn save_avi<Output: Write>(
+		self,
+		frames: Option<Frames>,
+		output: Output,
+		settings: &AnimSettings,
+	) -> AppResult<()> {
+		let (images, fps) = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		let geometry = images
+			.first()
+			.ok_or_else(|| {
+				AppError::FrameError(String::from("No frames found to save"))
+			})?
+			.geometry;
+		AviEncoder::new(fps, geometry, output, settings)
+			.save(images, self.settings.input_state)?;
+		Ok(())
+	}
+
+	/**
+	 * Save frames as pages of a PDF file.
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @param   settings
+	 * @return  Result
+	 */
+	fn save_pdf<Output: Write>(
+		self,
+		frames: Option<Frames>,
+		output: Output,
+		settings: &AnimSettings,
+	) -> AppResult<()> {
+		let (images, _) = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		PdfEncoder::new(output, settings).save(images, self.settings.input_state)
+	}
+
+	/**
+	 * Save frames by piping them into the encoder command.
+	 *
+	 * @param   frames (Option)
+	 * @param   path
+	 * @param   settings
+	 * @return  Result
+	 */
+	fn save_exec(
+		self,
+		frames: Option<Frames>,
+		path: &Path,
+		settings: &AnimSettings,
+	) -> AppResult<()> {
+		let (images, fps) = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		let geometry = images
+			.first()
+			.ok_or_else(|| {
+				AppError::FrameError(String::from("No frames found to save"))
+			})?
+			.geometry;
+		ExecEncoder::new(fps, geometry, path, settings)
+			.save(images, self.settings.input_state)?;
+		info!("Frames piped into: {:?}", path);
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -647,7 +1637,11 @@ mod tests {
 		let window = TestWindow::default();
 		let app = App::new(Some(window), &settings);
 		let images = app.get_frames()?.0;
-		app.save_gif(Some((images.clone(), 10)), File::create("test.gif")?)?;
+		app.save_gif(
+			Some((images.clone(), 10)),
+			File::create("test.gif")?,
+			&settings.anim,
+		)?;
 		app.edit_anim(File::open("test.gif")?, Path::new("test.gif"))?;
 		let dir = env::current_dir()?;
 		settings.split.dir = PathBuf::from(dir.to_str().unwrap_or_default());
@@ -656,8 +1650,48 @@ mod tests {
 		let app = App::new(Some(window), &settings);
 		app.split_anim(File::open("test.gif")?)?;
 		fs::remove_file("test.gif")?;
-		app.save_apng(Some((images.clone(), 20)), File::create("test.apng")?)?;
+		app.save_apng(
+			Some((images.clone(), 20)),
+			File::create("test.apng")?,
+			&settings.anim,
+		)?;
 		fs::remove_file("test.apng")?;
+		app.save_webp(
+			Some((images.clone(), 20)),
+			File::create("test.webp")?,
+			&settings.anim,
+		)?;
+		app.edit_anim(File::open("test.webp")?, Path::new("test.webp"))?;
+		fs::remove_file("test.webp")?;
+		app.save_y4m(
+			Some((images.clone(), 20)),
+			File::create("test.y4m")?,
+			&settings.anim,
+		)?;
+		fs::remove_file("test.y4m")?;
+		app.save_avi(
+			Some((images.clone(), 20)),
+			File::create("test.avi")?,
+			&settings.anim,
+		)?;
+		fs::remove_file("test.avi")?;
+		app.save_tiff(Some(images.clone()), File::create("test.tiff")?)?;
+		fs::remove_file("test.tiff")?;
+		app.save_pdf(
+			Some((images.clone(), 20)),
+			File::create("test.pdf")?,
+			&settings.anim,
+		)?;
+		fs::remove_file("test.pdf")?;
+		let mut marked = images.clone();
+		if let Some(image) = marked.last_mut() {
+			image.marker = true;
+		}
+		settings.save.file.format = FileFormat::Gif;
+		settings.anim.max_size = Some(1);
+		let markers = App::new(Some(window), &settings)
+			.save_output((None, Some((marked, 20))), io::sink())?;
+		assert_eq!(Markers::new(vec![(images.len() - 1) / 4]), markers);
 		for i in 0..images.len() {
 			let path = PathBuf::from(format!("frame_{}.png", i));
 			if path.exists() {
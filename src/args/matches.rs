@@ -102,6 +102,16 @@ impl<'a> ArgMatches<'a> {
 			.map_or(false, |s| s.to_lowercase() == "true")
 	}
 
+	/**
+	 * Check the argument is given on the command line or environment.
+	 *
+	 * @param  name
+	 * @return bool
+	 */
+	pub fn is_explicit(&self, name: &'a str) -> bool {
+		self.args.occurrences_of(name) > 0 || self.get_env(name).is_ok()
+	}
+
 	/**
 	 * Get the number of times an argument was used at runtime.
 	 *
@@ -166,6 +176,8 @@ mod tests {
 		env::set_var(&key, "true");
 		assert_eq!(true, matches.is_present("test"));
 		assert_eq!(false, matches.is_present("test_"));
+		assert_eq!(true, matches.is_explicit("test"));
+		assert_eq!(false, matches.is_explicit("test_"));
 		env::set_var(&key, "5");
 		assert_eq!(5, matches.occurrences_of("test"));
 		assert_eq!(0, matches.occurrences_of("test_"));
@@ -1,7 +1,11 @@
 pub mod matches;
 pub mod parser;
 use crate::anim::{AnimFormat, AnimMode};
+use crate::edit::settings::EditSettings;
 use crate::file::format::FileFormat;
+use crate::image::geometry::Geometry;
+use crate::record::settings::{RecordTime, StartTrigger, StopTrigger};
+use bytesize::ByteSize;
 use clap::{App, AppSettings, Arg, ArgMatches, Shell, SubCommand};
 use std::io::Write;
 use std::str::FromStr;
@@ -146,32 +150,88 @@ where
 				args.record
 					.subcommand(
 						Self::get_anim_args(AnimMode::Record(AnimFormat::Gif))
-							.subcommand(Self::get_save_args(FileFormat::Gif)),
+							.subcommand(Self::get_save_args(FileFormat::Gif, true)),
 					)
 					.subcommand(
 						Self::get_anim_args(AnimMode::Record(AnimFormat::Apng))
-							.subcommand(Self::get_save_args(FileFormat::Apng)),
+							.subcommand(Self::get_save_args(FileFormat::Apng, true)),
 					)
-					.subcommand(Self::get_save_args(FileFormat::Gif)),
+					.subcommand(
+						Self::get_anim_args(AnimMode::Record(AnimFormat::WebP))
+							.subcommand(Self::get_save_args(FileFormat::WebP, true)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Record(AnimFormat::Y4m))
+							.subcommand(Self::get_save_args(FileFormat::Y4m, true)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Record(AnimFormat::Avi))
+							.subcommand(Self::get_save_args(FileFormat::Avi, true)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Record(AnimFormat::Tiff))
+							.subcommand(Self::get_save_args(FileFormat::Tiff, true)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Record(AnimFormat::Pdf))
+							.subcommand(Self::get_save_args(FileFormat::Pdf, true)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Record(AnimFormat::Exec))
+							.subcommand(Self::get_save_args(FileFormat::Exec, true)),
+					)
+					.subcommand(Self::get_save_args(FileFormat::Gif, true)),
 			)
 			.subcommand(Self::get_image_args(args.split, false))
-			.subcommand(args.make.subcommand(Self::get_save_args(FileFormat::Gif)))
+			.subcommand(
+				args.make
+					.subcommand(Self::get_save_args(FileFormat::Gif, true)),
+			)
 			.subcommand(Self::get_image_args(args.capture, true))
 			.subcommand(Self::get_image_args(
 				args.edit
 					.subcommand(
 						Self::get_anim_args(AnimMode::Edit(AnimFormat::Gif))
-							.subcommand(Self::get_save_args(FileFormat::Gif)),
+							.subcommand(Self::get_save_args(FileFormat::Gif, false)),
 					)
 					.subcommand(
 						Self::get_anim_args(AnimMode::Edit(AnimFormat::Apng))
-							.subcommand(Self::get_save_args(FileFormat::Apng)),
+							.subcommand(Self::get_save_args(
+								FileFormat::Apng,
+								false,
+							)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Edit(AnimFormat::WebP))
+							.subcommand(Self::get_save_args(
+								FileFormat::WebP,
+								false,
+							)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Edit(AnimFormat::Y4m))
+							.subcommand(Self::get_save_args(FileFormat::Y4m, false)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Edit(AnimFormat::Avi))
+							.subcommand(Self::get_save_args(FileFormat::Avi, false)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Edit(AnimFormat::Pdf))
+							.subcommand(Self::get_save_args(FileFormat::Pdf, false)),
+					)
+					.subcommand(
+						Self::get_anim_args(AnimMode::Edit(AnimFormat::Exec))
+							.subcommand(Self::get_save_args(
+								FileFormat::Exec,
+								false,
+							)),
 					),
 				true,
 			))
 			.subcommand(
 				args.analyze
-					.subcommand(Self::get_save_args(FileFormat::Txt)),
+					.subcommand(Self::get_save_args(FileFormat::Txt, false)),
 			)
 			.subcommand(args.view)
 			.subcommand(args.misc)
@@ -260,6 +320,14 @@ where
 					.help("Set the cancel keys")
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("marker-keys")
+					.long("marker-keys")
+					.value_name("KEYS")
+					.help("Set the keys for adding chapter markers")
+					.takes_value(true)
+					.hidden(capture),
+			)
 			.arg(
 				Arg::with_name("mouse")
 					.short("m")
@@ -310,6 +378,203 @@ where
 					.takes_value(true)
 					.hidden(capture),
 			)
+			.arg(
+				Arg::with_name("show-keys")
+					.long("show-keys")
+					.value_name("CORNER")
+					.possible_values(&[
+						"top-left",
+						"top-right",
+						"bottom-left",
+						"bottom-right",
+					])
+					.help("Show the pressed keys on the recording")
+					.min_values(0)
+					.require_equals(true)
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("show-clicks")
+					.long("show-clicks")
+					.help("Show the mouse clicks on the recording")
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("events")
+					.long("events")
+					.value_name("FILE")
+					.help("Write the input events of the frames to a file")
+					.conflicts_with("windows")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("steps")
+					.long("steps")
+					.value_name("T")
+					.help("Record a step on each click or action key (e.g. 2s)")
+					.min_values(0)
+					.require_equals(true)
+					.validator(|v| match RecordTime::parse_duration(&v) {
+						Some(_) => Ok(()),
+						None => Err(String::from("Invalid duration")),
+					})
+					.conflicts_with("segment")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("step-dir")
+					.long("step-dir")
+					.value_name("DIRECTORY")
+					.requires("steps")
+					.help("Save the steps as images with a Markdown index")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("segment")
+					.long("segment")
+					.value_name("T")
+					.help("Split the recording into segments (e.g. 60s, 5m)")
+					.validator(|v| match RecordTime::parse_duration(&v) {
+						Some(_) => Ok(()),
+						None => Err(String::from("Invalid interval")),
+					})
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("tty")
+					.long("tty")
+					.requires("command")
+					.conflicts_with_all(&["virtual", "windows", "steps", "segment"])
+					.help("Record the command in a terminal without a display server")
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("tty-size")
+					.long("tty-size")
+					.value_name("COLSxROWS")
+					.default_value("80x24")
+					.help("Set the size of the terminal")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("tty-scale")
+					.long("tty-scale")
+					.value_name("SCALE")
+					.default_value("2")
+					.help("Set the scale of the terminal font")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("tty-theme")
+					.long("tty-theme")
+					.value_name("THEME")
+					.possible_values(&["dark", "light"])
+					.default_value("dark")
+					.help("Set the color theme of the terminal")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("virtual")
+					.long("virtual")
+					.value_name("WxH")
+					.requires("command")
+					.conflicts_with("windows")
+					.help("Record the command inside a virtual X display")
+					.validator(|v| {
+						let size = Geometry::parse(&v);
+						if v.split('x').count() == 2
+							&& size.width > 0 && size.height > 0
+						{
+							Ok(())
+						} else {
+							Err(String::from("Invalid display size"))
+						}
+					})
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("windows")
+					.long("windows")
+					.value_name("WINDOWS")
+					.help("Record multiple windows by ID or name (e.g. 0x1e00004,xterm)")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("compose")
+					.long("compose")
+					.requires("windows")
+					.help("Compose the recorded windows side by side")
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("start-on")
+					.long("start-on")
+					.value_name("TRIGGER")
+					.help("Start recording on a trigger (window:NAME, change)")
+					.validator(|v| match StartTrigger::parse(&v) {
+						Some(_) => Ok(()),
+						None => Err(String::from("Invalid start trigger")),
+					})
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("stop-on")
+					.long("stop-on")
+					.value_name("TRIGGER")
+					.help("Stop recording on a trigger (close, idle:T, file:PATH)")
+					.validator(|v| match StopTrigger::parse(&v) {
+						Some(_) => Ok(()),
+						None => Err(String::from("Invalid stop trigger")),
+					})
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("every")
+					.long("every")
+					.value_name("T")
+					.help("Capture an image periodically (e.g. 10s, 5m)")
+					.validator(|v| match RecordTime::parse_duration(&v) {
+						Some(_) => Ok(()),
+						None => Err(String::from("Invalid interval")),
+					})
+					.takes_value(true)
+					.hidden(!capture),
+			)
+			.arg(
+				Arg::with_name("count")
+					.long("count")
+					.value_name("NUM")
+					.requires("every")
+					.help("Set the number of images to capture periodically")
+					.validator(|v| match v.parse::<usize>() {
+						Ok(count) if count > 0 => Ok(()),
+						_ => Err(String::from("Invalid number of images")),
+					})
+					.takes_value(true)
+					.hidden(!capture),
+			)
+			.arg(
+				Arg::with_name("assemble")
+					.long("assemble")
+					.value_name("FORMAT")
+					.requires("every")
+					.possible_values(&["gif", "apng", "webp", "y4m", "avi", "tiff", "pdf"])
+					.help("Assemble the captured images into an animation")
+					.takes_value(true)
+					.hidden(!capture),
+			)
 			.arg(
 				Arg::with_name("countdown")
 					.short("c")
@@ -396,7 +661,12 @@ where
 					.default_value("75")
 					.help("Set the frame quality (1-100)")
 					.takes_value(true)
-					.hidden(mode.has_format(AnimFormat::Apng)),
+					.hidden(
+						mode.has_format(AnimFormat::Apng)
+							|| mode.has_format(AnimFormat::Y4m)
+							|| mode.has_format(AnimFormat::Tiff)
+							|| mode.has_format(AnimFormat::Exec),
+					),
 			)
 			.arg(
 				Arg::with_name("repeat")
@@ -412,7 +682,7 @@ where
 					.long("gifski")
 					.help("Use the gifski encoder")
 					.hidden(
-						mode.has_format(AnimFormat::Apng)
+						!mode.supports(AnimFormat::Gif)
 							|| cfg!(not(feature = "ski")),
 					),
 			)
@@ -421,10 +691,126 @@ where
 					.long("fast")
 					.help("Encode 3 times faster (gifski)")
 					.hidden(
-						mode.has_format(AnimFormat::Apng)
+						!mode.supports(AnimFormat::Gif)
 							|| cfg!(not(feature = "ski")),
 					),
 			)
+			.arg(
+				Arg::with_name("colors")
+					.long("colors")
+					.value_name("N")
+					.default_value("256")
+					.help("Set the maximum number of colors (2-256)")
+					.hidden(!mode.supports(AnimFormat::Gif))
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("dither")
+					.long("dither")
+					.value_name("METHOD")
+					.possible_values(&["none", "bayer", "floyd-steinberg"])
+					.default_value("none")
+					.help("Set the dithering method")
+					.hidden(!mode.supports(AnimFormat::Gif))
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("global-palette")
+					.long("global-palette")
+					.help("Use a global palette generated from the frames")
+					.hidden(!mode.supports(AnimFormat::Gif)),
+			)
+			.arg(
+				Arg::with_name("alpha-threshold")
+					.long("alpha-threshold")
+					.value_name("ALPHA")
+					.default_value("128")
+					.help("Set the alpha value below which pixels are transparent")
+					.hidden(!mode.supports(AnimFormat::Gif))
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("compression")
+					.long("compression")
+					.value_name("COMPRESSION")
+					.possible_values(if mode.has_format(AnimFormat::Tiff) {
+						&["none", "lzw", "deflate", "packbits"]
+					} else if mode == AnimMode::Make {
+						&[
+							"default", "fast", "best", "huffman", "rle", "none",
+							"lzw", "deflate", "packbits",
+						]
+					} else {
+						&["default", "fast", "best", "huffman", "rle"]
+					})
+					.default_value(if mode.has_format(AnimFormat::Tiff) {
+						"none"
+					} else {
+						"default"
+					})
+					.help("Set the compression level")
+					.hidden(
+						!mode.supports(AnimFormat::Apng)
+							&& !mode.supports(AnimFormat::Tiff),
+					)
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("filter")
+					.long("filter")
+					.value_name("FILTER")
+					.possible_values(&["none", "sub", "up", "avg", "paeth"])
+					.default_value("none")
+					.help("Set the filter algorithm")
+					.hidden(!mode.supports(AnimFormat::Apng))
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("indexed")
+					.long("indexed")
+					.help("Use an indexed palette when the colors fit")
+					.hidden(!mode.supports(AnimFormat::Apng)),
+			)
+			.arg(
+				Arg::with_name("lossless")
+					.long("lossless")
+					.help("Use the lossless compression")
+					.hidden(!mode.supports(AnimFormat::WebP)),
+			)
+			.arg(
+				Arg::with_name("chroma")
+					.long("chroma")
+					.value_name("CHROMA")
+					.possible_values(&["420", "444"])
+					.default_value("420")
+					.help("Set the chroma subsampling")
+					.hidden(!mode.supports(AnimFormat::Y4m))
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("page-size")
+					.long("page-size")
+					.value_name("SIZE")
+					.possible_values(&["fit", "a4", "letter"])
+					.default_value("fit")
+					.help("Set the page size")
+					.hidden(!mode.supports(AnimFormat::Pdf))
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("jpeg")
+					.long("jpeg")
+					.help("Embed the pages as JPEG")
+					.hidden(!mode.supports(AnimFormat::Pdf)),
+			)
+			.arg(
+				Arg::with_name("encoder-cmd")
+					.long("encoder-cmd")
+					.value_name("COMMAND")
+					.help("Set the command to pipe the raw frames into")
+					.hidden(!mode.supports(AnimFormat::Exec))
+					.takes_value(true),
+			)
 			.arg(
 				Arg::with_name("speed")
 					.short("s")
@@ -453,6 +839,35 @@ where
 					.hidden(!mode.is_edit())
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("dedupe")
+					.long("dedupe")
+					.value_name("THRESHOLD")
+					.help("Drop duplicate frames and merge their delays")
+					.min_values(0)
+					.require_equals(true)
+					.validator(|v| match v.parse::<f64>() {
+						Ok(_) => Ok(()),
+						Err(_) if v == "false" => Ok(()),
+						Err(_) => Err(String::from("Invalid dedupe threshold")),
+					})
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("preset")
+					.long("preset")
+					.value_name("PRESET")
+					.help("Use an encoding preset (chat, docs, hq, tiny)")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("max-size")
+					.long("max-size")
+					.value_name("SIZE")
+					.help("Tune the encoding to fit the output into the size")
+					.validator(|v| v.parse::<ByteSize>().map(|_| ()))
+					.takes_value(true),
+			)
 			.arg(
 				Arg::with_name("frames")
 					.value_name("FRAMES")
@@ -460,6 +875,7 @@ where
 					.min_values(1)
 					.hidden(mode != AnimMode::Make)
 					.default_value_if("dir", None, "-")
+					.default_value_if("cast", None, "-")
 					.required(mode == AnimMode::Make)
 					.empty_values(false)
 					.takes_value(true),
@@ -486,11 +902,58 @@ where
 					.long("format")
 					.value_name("FORMAT")
 					.help("Set the animation format")
-					.possible_values(&["gif", "apng"])
+					.possible_values(&[
+						"gif", "apng", "webp", "y4m", "avi", "tiff", "pdf", "exec",
+					])
 					.default_value("gif")
 					.hidden(mode != AnimMode::Make)
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("cast")
+					.long("cast")
+					.value_name("FILE")
+					.conflicts_with_all(&["frames", "dir"])
+					.help("Render an asciicast recording")
+					.hidden(mode != AnimMode::Make)
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("idle-limit")
+					.long("idle-limit")
+					.value_name("S")
+					.help("Limit the idle time between the cast events")
+					.hidden(mode != AnimMode::Make)
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("cast-speed")
+					.long("cast-speed")
+					.value_name("SPEED")
+					.default_value("1.0")
+					.help("Set the playback speed of the cast")
+					.hidden(mode != AnimMode::Make)
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("cast-scale")
+					.long("cast-scale")
+					.value_name("SCALE")
+					.default_value("2")
+					.help("Set the scale of the terminal font")
+					.hidden(mode != AnimMode::Make)
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("cast-theme")
+					.long("cast-theme")
+					.value_name("THEME")
+					.possible_values(&["cast", "dark", "light"])
+					.default_value("cast")
+					.help("Set the color theme of the terminal")
+					.hidden(mode != AnimMode::Make)
+					.takes_value(true),
+			)
 	}
 
 	/**
@@ -611,6 +1074,17 @@ where
 					.help("Set the sampling filter for scaling")
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("marker-range")
+					.long("marker-range")
+					.value_name("START:END")
+					.help("Keep the frames between the chapter markers")
+					.validator(|v| match EditSettings::parse_marker_range(&v) {
+						Some(_) => Ok(()),
+						None => Err(String::from("Invalid marker range")),
+					})
+					.takes_value(true),
+			)
 	}
 
 	/**
@@ -637,6 +1111,11 @@ where
 					.help("Set the output directory")
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("markers")
+					.long("markers")
+					.help("Split the animation at the chapter markers"),
+			)
 	}
 
 	/**
@@ -734,7 +1213,8 @@ where
 						.takes_value(true),
 				)
 				.subcommand(
-					Self::get_save_args(FileFormat::Png).settings(&save_settings),
+					Self::get_save_args(FileFormat::Png, false)
+						.settings(&save_settings),
 				),
 		)
 		.subcommand(
@@ -751,7 +1231,8 @@ where
 						.takes_value(true),
 				)
 				.subcommand(
-					Self::get_save_args(FileFormat::Jpg).settings(&save_settings),
+					Self::get_save_args(FileFormat::Jpg, false)
+						.settings(&save_settings),
 				),
 		)
 		.subcommand(
@@ -759,23 +1240,76 @@ where
 				.about("Use the BMP encoder")
 				.help_message("Print help information")
 				.subcommand(
-					Self::get_save_args(FileFormat::Bmp).settings(&save_settings),
+					Self::get_save_args(FileFormat::Bmp, false)
+						.settings(&save_settings),
 				),
 		)
 		.subcommand(
 			SubCommand::with_name("ico")
 				.about("Use the ICO encoder")
 				.help_message("Print help information")
+				.arg(
+					Arg::with_name("sizes")
+						.long("sizes")
+						.value_name("SIZES")
+						.default_value("16,24,32,48,64,128,256")
+						.help("Set the sizes of the icons")
+						.validator(|v| {
+							if v.split(',').all(|size| {
+								matches!(size.trim().parse::<u32>(),
+									Ok(size) if (1..=256).contains(&size))
+							}) {
+								Ok(())
+							} else {
+								Err(String::from("Invalid icon sizes (1-256)"))
+							}
+						})
+						.takes_value(true),
+				)
+				.arg(
+					Arg::with_name("filter")
+						.long("filter")
+						.value_name("FILTER")
+						.possible_values(&[
+							"nearest",
+							"triangle",
+							"catmull-rom",
+							"gaussian",
+							"lanczos3",
+						])
+						.default_value("lanczos3")
+						.help("Set the sampling filter for scaling")
+						.takes_value(true),
+				)
+				.arg(
+					Arg::with_name("favicon-set")
+						.long("favicon-set")
+						.value_name("DIR")
+						.help("Save the icon sizes as PNG along with the ICO")
+						.takes_value(true),
+				)
 				.subcommand(
-					Self::get_save_args(FileFormat::Ico).settings(&save_settings),
+					Self::get_save_args(FileFormat::Ico, false)
+						.settings(&save_settings),
 				),
 		)
 		.subcommand(
 			SubCommand::with_name("tiff")
 				.about("Use the TIFF encoder")
 				.help_message("Print help information")
+				.arg(
+					Arg::with_name("compression")
+						.short("c")
+						.long("compression")
+						.value_name("COMPRESSION")
+						.possible_values(&["none", "lzw", "deflate", "packbits"])
+						.default_value("none")
+						.help("Set the compression method")
+						.takes_value(true),
+				)
 				.subcommand(
-					Self::get_save_args(FileFormat::Tiff).settings(&save_settings),
+					Self::get_save_args(FileFormat::Tiff, false)
+						.settings(&save_settings),
 				),
 		)
 		.subcommand(
@@ -783,7 +1317,8 @@ where
 				.about("Use the TGA encoder")
 				.help_message("Print help information")
 				.subcommand(
-					Self::get_save_args(FileFormat::Tga).settings(&save_settings),
+					Self::get_save_args(FileFormat::Tga, false)
+						.settings(&save_settings),
 				),
 		)
 		.subcommand(
@@ -816,7 +1351,7 @@ where
 						.takes_value(true),
 				)
 				.subcommand(
-					Self::get_save_args(FileFormat::Pnm(String::from("ppm")))
+					Self::get_save_args(FileFormat::Pnm(String::from("ppm")), false)
 						.settings(&save_settings),
 				),
 		)
@@ -825,19 +1360,23 @@ where
 				.about("Use the farbfeld encoder")
 				.help_message("Print help information")
 				.subcommand(
-					Self::get_save_args(FileFormat::Ff).settings(&save_settings),
+					Self::get_save_args(FileFormat::Ff, false)
+						.settings(&save_settings),
 				),
 		)
-		.subcommand(Self::get_save_args(FileFormat::Any).settings(&save_settings))
+		.subcommand(
+			Self::get_save_args(FileFormat::Any, false).settings(&save_settings),
+		)
 	}
 
 	/**
 	 * Get save subcommand arguments.
 	 *
 	 * @param  file_format
+	 * @param  record
 	 * @return App
 	 */
-	fn get_save_args(file_format: FileFormat) -> App<'a, 'b> {
+	fn get_save_args(file_format: FileFormat, record: bool) -> App<'a, 'b> {
 		SubCommand::with_name("save")
 			.about("Save the output file(s)")
 			.help_message("Print help information")
@@ -846,9 +1385,11 @@ where
 				Arg::with_name("file")
 					.value_name("FILE")
 					.default_value(
-						Box::leak(file_format.into_file().path.into_boxed_path())
-							.to_str()
-							.unwrap_or_default(),
+						Box::leak(
+							file_format.into_file(record).path.into_boxed_path(),
+						)
+						.to_str()
+						.unwrap_or_default(),
 					)
 					.help("Set the output file"),
 			)
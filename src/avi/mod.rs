@@ -0,0 +1,313 @@
+pub mod settings;
+
+use crate::anim::settings::AnimSettings;
+use crate::app::{AppError, AppResult};
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use crate::util::state::InputState;
+use image::jpeg::JpegEncoder;
+use image::{ColorType, ExtendedColorType};
+use std::convert::TryInto;
+use std::io::{self, Write};
+
+/* Flag of the main header for indicating the index */
+const AVIF_HASINDEX: u32 = 0x10;
+/* Flag of the index entries for marking the key frames */
+const AVIIF_KEYFRAME: u32 = 0x10;
+/* Sizes of the header chunks */
+const AVIH_SIZE: u32 = 56;
+const STRH_SIZE: u32 = 56;
+const STRF_SIZE: u32 = 40;
+/* Size limit of the AVI 1.0 files (1 GiB) */
+const AVI_SIZE_LIMIT: u64 = 1 << 30;
+
+/* AVI encoder that stores the frames as JPEG (MJPEG) */
+pub struct AviEncoder<'a, Output: Write> {
+	fps: u32,
+	geometry: Geometry,
+	output: Output,
+	settings: &'a AnimSettings,
+}
+
+impl<'a, Output: Write> AviEncoder<'a, Output> {
+	/**
+	 * Create a new AviEncoder object.
+	 *
+	 * @param  fps
+	 * @param  geometry
+	 * @param  output
+	 * @param  settings
+	 * @return AviEncoder
+	 */
+	pub fn new(
+		fps: u32,
+		geometry: Geometry,
+		output: Output,
+		settings: &'a AnimSettings,
+	) -> Self {
+		Self {
+			fps: fps.max(1),
+			geometry,
+			output,
+			settings,
+		}
+	}
+
+	/**
+	 * Encode images as frame and write to the AVI file.
+	 *
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	pub fn save(
+		mut self,
+		images: Vec<Image>,
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		let mut chunks = Vec::new();
+		let mut frames = Vec::new();
+		let mut elapsed = 0.;
+		for (i, image) in images.iter().enumerate() {
+			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
+			info!("Saving... ({:.1}%)\r", percentage);
+			debug!(
+				"Encoding... ({:.1}%) [{}/{}]\r",
+				percentage,
+				i + 1,
+				images.len()
+			);
+			io::stdout().flush()?;
+			if let Some(state) = input_state {
+				if state.check_cancel_keys() {
+					info!("\n");
+					warn!("User interrupt detected.");
+					panic!("Failed to write the frames")
+				}
+			}
+			let mut data = Vec::new();
+			JpegEncoder::new_with_quality(&mut data, self.settings.avi.jpg.quality)
+				.encode(
+					&image.get_data(ExtendedColorType::Rgb8),
+					image.geometry.width,
+					image.geometry.height,
+					ColorType::Rgb8,
+				)?;
+			chunks.push(data);
+			elapsed += image
+				.delay
+				.map_or(1000. / self.fps as f64, |delay| delay as f64);
+			let frame_count = (elapsed * self.fps as f64 / 1000.).round() as usize;
+			frames.resize(frame_count.max(frames.len()), i);
+		}
+		self.write(&chunks, &frames)?;
+		info!("\n");
+		Ok(())
+	}
+
+	/**
+	 * Get the sizes of the movi list, index and RIFF chunk.
+	 *
+	 * @param  chunks
+	 * @param  frames
+	 * @return Tuple of the sizes (Result)
+	 */
+	fn get_sizes(
+		chunks: &[Vec<u8>],
+		frames: &[usize],
+	) -> AppResult<(u32, u32, u32)> {
+		let strl_size = 4 + (8 + STRH_SIZE) + (8 + STRF_SIZE);
+		let hdrl_size = 4 + (8 + AVIH_SIZE) + (8 + strl_size);
+		let sizes = frames
+			.iter()
+			.try_fold(4_u64, |size, i| {
+				size.checked_add(
+					8 + (chunks[*i].len() + chunks[*i].len() % 2) as u64,
+				)
+			})
+			.and_then(|movi_size| {
+				let idx1_size = (frames.len() as u64).checked_mul(16)?;
+				let riff_size = (4 + 8 + hdrl_size as u64)
+					.checked_add(8 + movi_size)?
+					.checked_add(8 + idx1_size)?;
+				Some((
+					movi_size.try_into().ok()?,
+					idx1_size.try_into().ok()?,
+					riff_size.try_into().ok()?,
+				))
+			})
+			.ok_or_else(|| {
+				AppError::FrameError(String::from(
+					"AVI file exceeds the RIFF size limit (4 GiB)",
+				))
+			})?;
+		if u64::from(sizes.2) > AVI_SIZE_LIMIT {
+			warn!("AVI file exceeds 1 GiB, it might not play in some players.");
+		}
+		Ok(sizes)
+	}
+
+	/**
+	 * Write the headers, frame chunks and index to the output.
+	 *
+	 * @param  chunks
+	 * @param  frames
+	 * @return Result
+	 */
+	fn write(&mut self, chunks: &[Vec<u8>], frames: &[usize]) -> AppResult<()> {
+		let (movi_size, idx1_size, riff_size) = Self::get_sizes(chunks, frames)?;
+		let padded_len = |chunk: &Vec<u8>| (chunk.len() + chunk.len() % 2) as u32;
+		let max_chunk_size = chunks.iter().map(padded_len).max().unwrap_or_default();
+		let strl_size = 4 + (8 + STRH_SIZE) + (8 + STRF_SIZE);
+		let hdrl_size = 4 + (8 + AVIH_SIZE) + (8 + strl_size);
+		let (width, height) = (self.geometry.width, self.geometry.height);
+		self.write_fourcc("RIFF", riff_size)?;
+		self.output.write_all(b"AVI ")?;
+		self.write_fourcc("LIST", hdrl_size)?;
+		self.output.write_all(b"hdrl")?;
+		self.write_fourcc("avih", AVIH_SIZE)?;
+		self.write_values(&[
+			1_000_000 / self.fps,
+			max_chunk_size.saturating_mul(self.fps),
+			0,
+			AVIF_HASINDEX,
+			frames.len() as u32,
+			0,
+			1,
+			max_chunk_size,
+			width,
+			height,
+			0,
+			0,
+			0,
+			0,
+		])?;
+		self.write_fourcc("LIST", strl_size)?;
+		self.output.write_all(b"strl")?;
+		self.write_fourcc("strh", STRH_SIZE)?;
+		self.output.write_all(b"vidsMJPG")?;
+		self.write_values(&[
+			0,
+			0,
+			0,
+			1,
+			self.fps,
+			0,
+			frames.len() as u32,
+			max_chunk_size,
+			u32::MAX,
+			0,
+		])?;
+		for value in &[0, 0, width as u16, height as u16] {
+			self.output.write_all(&u16::to_le_bytes(*value))?;
+		}
+		self.write_fourcc("strf", STRF_SIZE)?;
+		self.write_values(&[STRF_SIZE, width, height])?;
+		self.output.write_all(&u16::to_le_bytes(1))?;
+		self.output.write_all(&u16::to_le_bytes(24))?;
+		self.output.write_all(b"MJPG")?;
+		self.write_values(&[
+			width.saturating_mul(height).saturating_mul(3),
+			0,
+			0,
+			0,
+			0,
+		])?;
+		self.write_fourcc("LIST", movi_size)?;
+		self.output.write_all(b"movi")?;
+		for i in frames {
+			let chunk = &chunks[*i];
+			self.write_fourcc("00dc", chunk.len() as u32)?;
+			self.output.write_all(chunk)?;
+			if padded_len(chunk) as usize > chunk.len() {
+				self.output.write_all(&[0])?;
+			}
+		}
+		self.write_fourcc("idx1", idx1_size)?;
+		let mut offset = 4;
+		for i in frames {
+			let chunk = &chunks[*i];
+			self.output.write_all(b"00dc")?;
+			self.write_values(&[AVIIF_KEYFRAME, offset, chunk.len() as u32])?;
+			offset += 8 + padded_len(chunk);
+		}
+		Ok(())
+	}
+
+	/**
+	 * Write a four-character code followed by a size.
+	 *
+	 * @param  fourcc
+	 * @param  size
+	 * @return Result
+	 */
+	fn write_fourcc(&mut self, fourcc: &str, size: u32) -> AppResult<()> {
+		self.output.write_all(fourcc.as_bytes())?;
+		self.write_values(&[size])
+	}
+
+	/**
+	 * Write the values as little-endian 32-bit integers.
+	 *
+	 * @param  values
+	 * @return Result
+	 */
+	fn write_values(&mut self, values: &[u32]) -> AppResult<()> {
+		for value in values {
+			self.output.write_all(&value.to_le_bytes())?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::{Bgra, GenericImageView};
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_avi_encoder() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 8, 8);
+		let mut images = vec![
+			Image::new(vec![Bgra::from([255, 0, 0, 255]); 64], false, geometry),
+			Image::new(vec![Bgra::from([0, 0, 255, 255]); 64], false, geometry),
+		];
+		images[0].delay = Some(300);
+		let mut output = Vec::new();
+		AviEncoder::new(10, geometry, &mut output, &AnimSettings::default())
+			.save(images, None)?;
+		let read_u32 = |offset: usize| {
+			u32::from_le_bytes(output[offset..offset + 4].try_into().unwrap())
+		};
+		assert_eq!(b"RIFF", &output[0..4]);
+		assert_eq!(output.len() as u32 - 8, read_u32(4));
+		assert_eq!(b"AVI ", &output[8..12]);
+		assert_eq!(100_000, read_u32(32));
+		assert_eq!(4, read_u32(48));
+		let movi = output
+			.windows(4)
+			.position(|v| v == b"movi")
+			.unwrap_or_default();
+		let idx1 = output
+			.windows(4)
+			.position(|v| v == b"idx1")
+			.unwrap_or_default();
+		assert_eq!(4 * 16, read_u32(idx1 + 4));
+		let last_offset = read_u32(idx1 + 8 + 3 * 16 + 8) as usize;
+		let last_size = read_u32(idx1 + 8 + 3 * 16 + 12) as usize;
+		assert_eq!(b"00dc", &output[movi + last_offset..movi + last_offset + 4]);
+		let frame = image::load_from_memory(
+			&output[movi + last_offset + 8..movi + last_offset + 8 + last_size],
+		)?;
+		assert_eq!((8, 8), frame.dimensions());
+		assert!(frame.get_pixel(4, 4)[0] > 200);
+		assert!(frame.get_pixel(4, 4)[2] < 50);
+		let chunks = [vec![0; 1 << 20]];
+		assert!(AviEncoder::<Vec<u8>>::get_sizes(&chunks, &[0; 1024]).is_ok());
+		assert!(matches!(
+			AviEncoder::<Vec<u8>>::get_sizes(&chunks, &[0; 5000]),
+			Err(AppError::FrameError(_))
+		));
+		Ok(())
+	}
+}
@@ -0,0 +1,19 @@
+use crate::image::settings::JpgSettings;
+
+/* AVI (MJPEG) encoding settings */
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AviSettings {
+	pub jpg: JpgSettings,
+}
+
+impl AviSettings {
+	/**
+	 * Create a new AviSettings object.
+	 *
+	 * @param  jpg
+	 * @return AviSettings
+	 */
+	pub fn new(jpg: JpgSettings) -> Self {
+		Self { jpg }
+	}
+}
@@ -131,6 +131,7 @@ pub struct EditSettings {
 	pub convert: bool,
 	pub image: ImageSettings,
 	pub color: ColorSettings,
+	pub marker_range: Option<(usize, Option<usize>)>,
 }
 
 /* Default initialization values for EditSettings */
@@ -141,6 +142,7 @@ impl Default for EditSettings {
 			convert: false,
 			image: ImageSettings::default(),
 			color: ColorSettings::default(),
+			marker_range: None,
 		}
 	}
 }
@@ -153,6 +155,7 @@ impl EditSettings {
 	 * @param  convert
 	 * @param  image
 	 * @param  color
+	 * @param  marker_range (Option)
 	 * @return EditSettings
 	 */
 	pub fn new(
@@ -160,12 +163,14 @@ impl EditSettings {
 		convert: bool,
 		image: ImageSettings,
 		color: ColorSettings,
+		marker_range: Option<(usize, Option<usize>)>,
 	) -> Self {
 		Self {
 			path,
 			convert,
 			image,
 			color,
+			marker_range,
 		}
 	}
 
@@ -216,11 +221,30 @@ impl EditSettings {
 					parser.parse("contrast", ColorSettings::default().contrast),
 					parser.parse("brightness", ColorSettings::default().brightness),
 				),
+				matches
+					.value_of("marker-range")
+					.and_then(Self::parse_marker_range),
 			),
 			None => Self::default(),
 		}
 	}
 
+	/**
+	 * Parse the marker range from a string.
+	 *
+	 * @param  range (e.g. 1:3, 2:, 2)
+	 * @return Tuple of start and end (Option)
+	 */
+	pub fn parse_marker_range(range: &str) -> Option<(usize, Option<usize>)> {
+		let mut values = range.split(':');
+		let start = values.next()?.trim().parse().ok()?;
+		match values.next().map(str::trim) {
+			Some("") => Some((start, None)),
+			Some(end) => Some((start, Some(end.parse().ok()?))),
+			None => Some((start, Some(start + 1))),
+		}
+	}
+
 	/**
 	 * Get ImageOps object from EditSettings.
 	 *
@@ -262,6 +286,11 @@ mod tests {
 					.takes_value(true),
 			)
 			.arg(Arg::with_name("filter").long("filter").takes_value(true))
+			.arg(
+				Arg::with_name("marker-range")
+					.long("marker-range")
+					.takes_value(true),
+			)
 			.get_matches_from(vec![
 				"test",
 				"x",
@@ -288,6 +317,8 @@ mod tests {
 				"2",
 				"--filter",
 				"triangle",
+				"--marker-range",
+				"1:3",
 			]);
 		let edit_settings = EditSettings::from_parser(ArgParser::from_args(&args));
 		assert_eq!(PathBuf::from("x"), edit_settings.path);
@@ -303,5 +334,9 @@ mod tests {
 		assert_eq!(3, edit_settings.color.hue);
 		assert_eq!(-5., edit_settings.color.contrast);
 		assert_eq!("Triangle", format!("{:?}", edit_settings.image.filter));
+		assert_eq!(Some((1, Some(3))), edit_settings.marker_range);
+		assert_eq!(Some((2, None)), EditSettings::parse_marker_range("2:"));
+		assert_eq!(Some((2, Some(3))), EditSettings::parse_marker_range("2"));
+		assert_eq!(None, EditSettings::parse_marker_range("x:1"));
 	}
 }
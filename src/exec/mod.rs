@@ -0,0 +1,183 @@
+pub mod settings;
+
+use crate::anim::settings::AnimSettings;
+use crate::app::{AppError, AppResult};
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use crate::util::command::Command;
+use crate::util::state::InputState;
+use image::ExtendedColorType;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::thread;
+
+/* Encoder that pipes the raw frames into an external command */
+pub struct ExecEncoder<'a> {
+	fps: u32,
+	geometry: Geometry,
+	output: &'a Path,
+	settings: &'a AnimSettings,
+}
+
+impl<'a> ExecEncoder<'a> {
+	/**
+	 * Create a new ExecEncoder object.
+	 *
+	 * @param  fps
+	 * @param  geometry
+	 * @param  output
+	 * @param  settings
+	 * @return ExecEncoder
+	 */
+	pub fn new(
+		fps: u32,
+		geometry: Geometry,
+		output: &'a Path,
+		settings: &'a AnimSettings,
+	) -> Self {
+		Self {
+			fps: fps.max(1),
+			geometry,
+			output,
+			settings,
+		}
+	}
+
+	/**
+	 * Run the encoder command and write the frames to its standard input.
+	 *
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	pub fn save(
+		self,
+		images: Vec<Image>,
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		let command = self
+			.settings
+			.exec
+			.get_command(self.geometry, self.fps, self.output)
+			.ok_or_else(|| {
+				AppError::CommandError(String::from("No encoder command specified"))
+			})?;
+		info!("Running the encoder command...");
+		debug!("{}", command);
+		let mut child =
+			Command::from(command.as_str()).spawn_piped().map_err(|e| {
+				AppError::CommandError(format!(
+					"Failed to run the encoder command: {}",
+					e
+				))
+			})?;
+		let stderr = child.stderr.take().map(|mut stderr| {
+			thread::spawn(move || {
+				let mut output = String::new();
+				stderr.read_to_string(&mut output).map(|_| output)
+			})
+		});
+		let written = match child.stdin.take() {
+			Some(stdin) => self.write_frames(stdin, &images, input_state),
+			None => Ok(()),
+		};
+		let status = child.wait()?;
+		if !status.success() {
+			let stderr = stderr
+				.and_then(|thread| thread.join().ok())
+				.and_then(|output| output.ok())
+				.unwrap_or_default();
+			return Err(AppError::CommandError(format!(
+				"Encoder command failed with {}: {}",
+				status,
+				stderr.trim()
+			)));
+		}
+		written
+	}
+
+	/**
+	 * Write the images as raw RGBA frames at the constant frame rate.
+	 *
+	 * @param  output
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	fn write_frames<Output: Write>(
+		&self,
+		mut output: Output,
+		images: &[Image],
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		let mut elapsed = 0.;
+		let mut frame_count = 0;
+		for (i, image) in images.iter().enumerate() {
+			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
+			info!("Saving... ({:.1}%)\r", percentage);
+			debug!(
+				"Encoding... ({:.1}%) [{}/{}]\r",
+				percentage,
+				i + 1,
+				images.len()
+			);
+			io::stdout().flush()?;
+			if let Some(state) = input_state {
+				if state.check_cancel_keys() {
+					info!("\n");
+					warn!("User interrupt detected.");
+					panic!("Failed to write the frames")
+				}
+			}
+			elapsed += image
+				.delay
+				.map_or(1000. / self.fps as f64, |delay| delay as f64);
+			let data = image.get_data(ExtendedColorType::Rgba8);
+			while frame_count < (elapsed * self.fps as f64 / 1000.).round() as u64 {
+				output.write_all(&data)?;
+				frame_count += 1;
+			}
+		}
+		info!("\n");
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::exec::settings::ExecSettings;
+	use image::Bgra;
+	use pretty_assertions::assert_eq;
+	use std::fs;
+	#[test]
+	fn test_exec_encoder() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 2, 1);
+		let mut images = vec![
+			Image::new(vec![Bgra::from([0, 0, 0, 255]); 2], false, geometry),
+			Image::new(vec![Bgra::from([255, 0, 0, 255]); 2], false, geometry),
+		];
+		images[1].delay = Some(200);
+		let mut settings = AnimSettings {
+			exec: ExecSettings::new(Some(String::from("cat > {out}.{w}x{h}.{fps}"))),
+			..AnimSettings::default()
+		};
+		ExecEncoder::new(10, geometry, Path::new("test"), &settings)
+			.save(images.clone(), None)?;
+		let data = fs::read("test.2x1.10")?;
+		fs::remove_file("test.2x1.10")?;
+		assert_eq!(3 * 2 * 4, data.len());
+		assert_eq!(vec![0, 0, 255, 255], data[data.len() - 4..].to_vec());
+		settings.exec =
+			ExecSettings::new(Some(String::from("echo failed >&2; exit 3")));
+		match ExecEncoder::new(10, geometry, Path::new("-"), &settings)
+			.save(images, None)
+		{
+			Err(AppError::CommandError(message)) => {
+				assert!(message.ends_with("exit status: 3: failed"))
+			}
+			_ => panic!("Expected a command error"),
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,100 @@
+use crate::image::geometry::Geometry;
+use std::path::Path;
+
+/* External encoder settings */
+#[derive(Clone, Debug, Default)]
+pub struct ExecSettings {
+	pub command: Option<String>,
+}
+
+impl ExecSettings {
+	/**
+	 * Create a new ExecSettings object.
+	 *
+	 * @param  command (Option)
+	 * @return ExecSettings
+	 */
+	pub fn new(command: Option<String>) -> Self {
+		Self { command }
+	}
+
+	/**
+	 * Quote the value for using it as a single shell word.
+	 *
+	 * @param  value
+	 * @return String
+	 */
+	fn quote(value: &str) -> String {
+		if !value.is_empty()
+			&& value
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || "_-+=.,:/@%".contains(c))
+		{
+			value.to_string()
+		} else {
+			format!("'{}'", value.replace('\'', "'\\''"))
+		}
+	}
+
+	/**
+	 * Get the encoder command with the placeholders replaced.
+	 * The substituted values are quoted for the shell.
+	 *
+	 * @param  geometry
+	 * @param  fps
+	 * @param  output
+	 * @return String (Option)
+	 */
+	pub fn get_command(
+		&self,
+		geometry: Geometry,
+		fps: u32,
+		output: &Path,
+	) -> Option<String> {
+		self.command.as_ref().map(|command| {
+			command
+				.replace("{w}", &Self::quote(&geometry.width.to_string()))
+				.replace("{h}", &Self::quote(&geometry.height.to_string()))
+				.replace("{fps}", &Self::quote(&fps.to_string()))
+				.replace("{out}", &Self::quote(&output.to_string_lossy()))
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_exec_settings() {
+		let settings = ExecSettings::new(Some(String::from(
+			"ffmpeg -s {w}x{h} -r {fps} -i - {out}",
+		)));
+		assert_eq!(
+			Some(String::from("ffmpeg -s 20x10 -r 30 -i - out.mp4")),
+			settings.get_command(
+				Geometry::new(0, 0, 20, 10),
+				30,
+				Path::new("out.mp4")
+			)
+		);
+		assert_eq!(
+			Some(String::from(
+				"ffmpeg -s 20x10 -r 30 -i - 'my '\\''out'\\''.mp4'"
+			)),
+			settings.get_command(
+				Geometry::new(0, 0, 20, 10),
+				30,
+				Path::new("my 'out'.mp4")
+			)
+		);
+		assert_eq!(
+			None,
+			ExecSettings::default().get_command(
+				Geometry::default(),
+				30,
+				Path::new("-")
+			)
+		);
+	}
+}
@@ -10,6 +10,11 @@ pub enum FileFormat {
 	Any,
 	Gif,
 	Apng,
+	WebP,
+	Y4m,
+	Avi,
+	Pdf,
+	Exec,
 	Png,
 	Jpg,
 	Bmp,
@@ -35,6 +40,11 @@ impl FromStr for FileFormat {
 		match s {
 			"gif" => Ok(Self::Gif),
 			"apng" => Ok(Self::Apng),
+			"webp" => Ok(Self::WebP),
+			"y4m" => Ok(Self::Y4m),
+			"avi" => Ok(Self::Avi),
+			"pdf" => Ok(Self::Pdf),
+			"exec" => Ok(Self::Exec),
 			"png" => Ok(Self::Png),
 			"jpg" => Ok(Self::Jpg),
 			"bmp" => Ok(Self::Bmp),
@@ -79,6 +89,18 @@ impl FileFormat {
 					}
 				} else if matches.is_present("gif") {
 					Self::Gif
+				} else if matches.is_present("apng") {
+					Self::Apng
+				} else if matches.is_present("webp") {
+					Self::WebP
+				} else if matches.is_present("y4m") {
+					Self::Y4m
+				} else if matches.is_present("avi") {
+					Self::Avi
+				} else if matches.is_present("pdf") {
+					Self::Pdf
+				} else if matches.is_present("exec") {
+					Self::Exec
 				} else if matches.is_present("ff") {
 					Self::Ff
 				} else if matches.is_present("tiff") {
@@ -107,6 +129,18 @@ impl FileFormat {
 				if let Some(matches) = args.subcommand_matches("record") {
 					if matches.is_present("apng") {
 						Self::Apng
+					} else if matches.is_present("webp") {
+						Self::WebP
+					} else if matches.is_present("y4m") {
+						Self::Y4m
+					} else if matches.is_present("avi") {
+						Self::Avi
+					} else if matches.is_present("tiff") {
+						Self::Tiff
+					} else if matches.is_present("pdf") {
+						Self::Pdf
+					} else if matches.is_present("exec") {
+						Self::Exec
 					} else {
 						Self::Gif
 					}
@@ -120,13 +154,14 @@ impl FileFormat {
 	/**
 	 * Get default File from format.
 	 *
+	 * @param  record
 	 * @return File
 	 */
-	pub fn into_file(self) -> File {
+	pub fn into_file(self, record: bool) -> File {
 		File::new(
 			File::get_default_path(&format!(
 				"{}.{}",
-				self.get_default_file_name(),
+				self.get_default_file_name(record),
 				self.as_extension()
 			)),
 			self,
@@ -137,13 +172,21 @@ impl FileFormat {
 	/**
 	 * Get the default file name from format.
 	 *
+	 * @param  record
 	 * @return String
 	 */
-	fn get_default_file_name(&self) -> String {
+	fn get_default_file_name(&self, record: bool) -> String {
 		String::from(match self {
 			Self::Any => "output",
 			Self::Txt => "report",
-			Self::Gif | Self::Apng => "rec",
+			Self::Gif
+			| Self::Apng
+			| Self::WebP
+			| Self::Y4m
+			| Self::Avi
+			| Self::Pdf
+			| Self::Exec => "rec",
+			_ if record && self.is_multi_page() => "rec",
 			_ => "cap",
 		})
 	}
@@ -154,7 +197,39 @@ impl FileFormat {
 	 * @return bool
 	 */
 	pub fn is_animation(&self) -> bool {
-		self == &Self::Gif || self == &Self::Apng
+		matches!(
+			self,
+			Self::Gif
+				| Self::Apng | Self::WebP
+				| Self::Y4m | Self::Avi
+				| Self::Pdf | Self::Exec
+		)
+	}
+
+	/**
+	 * Check if the format can store the frames as multiple pages.
+	 *
+	 * @return bool
+	 */
+	pub fn is_multi_page(&self) -> bool {
+		matches!(self, Self::Tiff)
+	}
+
+	/**
+	 * Check if the format supports the given compression method.
+	 *
+	 * @param  compression
+	 * @return bool
+	 */
+	pub fn supports_compression(&self, compression: &str) -> bool {
+		match self {
+			Self::Apng | Self::Png => {
+				["default", "fast", "best", "huffman", "rle"].contains(&compression)
+			}
+			Self::Tiff => ["default", "none", "lzw", "deflate", "packbits"]
+				.contains(&compression),
+			_ => true,
+		}
 	}
 
 	/**
@@ -63,6 +63,28 @@ impl File {
 		}
 	}
 
+	/**
+	 * Get the path with the given information added to the file name.
+	 *
+	 * @param  path
+	 * @param  info
+	 * @return PathBuf
+	 */
+	pub fn get_path_with_info(path: &Path, info: &str) -> PathBuf {
+		path.with_file_name(format!(
+			"{}_{}{}",
+			path.file_stem()
+				.unwrap_or_default()
+				.to_str()
+				.unwrap_or_default(),
+			info,
+			path.extension().map_or(String::new(), |extension| format!(
+				".{}",
+				extension.to_str().unwrap_or_default()
+			))
+		))
+	}
+
 	/**
 	 * Get the default path for a file.
 	 *
@@ -129,12 +151,23 @@ mod tests {
 					.to_str()
 					.unwrap(),
 				FileFormat::from_args(&ArgMatches::new(&args), None)
-					.into_file()
+					.into_file(false)
 					.path
 					.to_str()
 					.unwrap()
 			);
 		}
+		assert_eq!(
+			File::get_default_path("rec.tiff"),
+			FileFormat::Tiff.into_file(true).path
+		);
+		assert_eq!(
+			File::get_default_path("cap.png"),
+			FileFormat::Png.into_file(true).path
+		);
+		assert!(FileFormat::Tiff.supports_compression("lzw"));
+		assert!(!FileFormat::Tiff.supports_compression("fast"));
+		assert!(!FileFormat::Apng.supports_compression("lzw"));
 		assert_eq!(
 			"Gif",
 			FileFormat::from_args(
@@ -143,6 +176,14 @@ mod tests {
 			)
 			.to_string()
 		);
+		assert_eq!(
+			PathBuf::from("x/test_1.gif"),
+			File::get_path_with_info(Path::new("x/test.gif"), "1")
+		);
+		assert_eq!(
+			PathBuf::from("test_x"),
+			File::get_path_with_info(Path::new("test"), "x")
+		);
 		for info in vec!["", "date", "timestamp"] {
 			let args = App::new("test")
 				.arg(Arg::with_name(info).long(&format!("--{}", info)))
@@ -12,6 +12,8 @@ use std::str::FromStr;
 #[derive(Debug)]
 pub struct SaveSettings {
 	pub file: File,
+	pub info: Option<FileInfo<'static>>,
+	base_path: PathBuf,
 }
 
 impl SaveSettings {
@@ -19,10 +21,21 @@ impl SaveSettings {
 	 * Create a new SaveSettings object.
 	 *
 	 * @param  file
+	 * @param  info (Option)
 	 * @return SaveSettings
 	 */
-	pub fn new(file: File) -> Self {
-		Self { file }
+	pub fn new(file: File, info: Option<FileInfo<'static>>) -> Self {
+		Self {
+			base_path: file.path.clone(),
+			file: match info {
+				Some(info) => File {
+					path: File::get_path_with_info(&file.path, &info.to_string()),
+					format: file.format,
+				},
+				None => file,
+			},
+			info,
+		}
 	}
 
 	/**
@@ -59,6 +72,7 @@ impl SaveSettings {
 				)
 				.unwrap_or(format)
 			},
+			matches.is_present("record") || matches.is_present("make"),
 		)
 	}
 
@@ -67,36 +81,49 @@ impl SaveSettings {
 	 *
 	 * @param  parser
 	 * @param  file_format
+	 * @param  record
 	 * @return SaveSettings
 	 */
-	fn from_parser(parser: ArgParser<'_>, file_format: FileFormat) -> Self {
+	fn from_parser(
+		parser: ArgParser<'_>,
+		file_format: FileFormat,
+		record: bool,
+	) -> Self {
 		match parser.args {
-			Some(matches) => {
-				let mut path =
-					PathBuf::from(matches.value_of("file").unwrap_or_default());
-				if let Some(info) = FileInfo::from_args(&matches) {
-					path.set_file_name(format!(
-						"{}_{}{}",
-						path.file_stem()
-							.unwrap_or_default()
-							.to_str()
-							.unwrap_or_default(),
-						info,
-						path.extension().map_or(String::new(), |extension| format!(
-							".{}",
-							extension.to_str().unwrap_or_default()
-						))
-					));
-				}
-				Self::new(File::new(
-					path,
+			Some(matches) => Self::new(
+				File::new(
+					PathBuf::from(matches.value_of("file").unwrap_or_default()),
 					file_format,
 					matches.is_present("with-extension"),
-				))
-			}
-			None => Self::new(file_format.into_file()),
+				),
+				match FileInfo::from_args(&matches) {
+					Some(FileInfo::Date(format)) => Some(FileInfo::Date(Box::leak(
+						format.to_string().into_boxed_str(),
+					))),
+					Some(FileInfo::Timestamp) => Some(FileInfo::Timestamp),
+					None => None,
+				},
+			),
+			None => Self::new(file_format.into_file(record), None),
 		}
 	}
+
+	/**
+	 * Get the path of an output file in a numbered sequence.
+	 * Index is always included since the file info might not be unique.
+	 *
+	 * @param  index
+	 * @return PathBuf
+	 */
+	pub fn get_sequence_path(&self, index: usize) -> PathBuf {
+		File::get_path_with_info(
+			&self.base_path,
+			&match self.info {
+				Some(info) => format!("{}_{}", info, index),
+				None => index.to_string(),
+			},
+		)
+	}
 }
 
 #[cfg(test)]
@@ -105,6 +132,7 @@ mod tests {
 	use crate::args::matches::ArgMatches;
 	use clap::{App, Arg, SubCommand};
 	use pretty_assertions::assert_eq;
+	use std::ffi::OsStr;
 	#[test]
 	fn test_save_settings() {
 		let args = App::new("test")
@@ -128,8 +156,24 @@ mod tests {
 		let save_settings = SaveSettings::from_parser(
 			ArgParser::from_subcommand(&matches, "save"),
 			FileFormat::from_args(&matches, None),
+			false,
 		);
 		assert!(save_settings.file.path.to_str().unwrap().contains("test_"));
 		assert_eq!(FileFormat::Jpg, save_settings.file.format);
+		assert!(save_settings
+			.get_sequence_path(1)
+			.to_str()
+			.unwrap()
+			.starts_with("test_"));
+		assert_ne!(
+			save_settings.get_sequence_path(1),
+			save_settings.get_sequence_path(2)
+		);
+		let save_settings =
+			SaveSettings::new(FileFormat::Png.into_file(false), None);
+		assert_eq!(
+			Some(OsStr::new("cap_2.png")),
+			save_settings.get_sequence_path(2).file_name()
+		);
 	}
 }
@@ -1,13 +1,19 @@
 pub mod encoder;
+pub mod palette;
+pub mod settings;
 #[cfg(feature = "ski")]
 pub mod ski;
 
+use crate::anim::delta::FrameDelta;
 use crate::anim::settings::AnimSettings;
 use crate::app::AppResult;
 use crate::gif::encoder::{Encoder, EncoderConfig};
+use crate::gif::palette::Palette;
+use crate::gif::settings::GifSettings;
+use crate::image::geometry::Geometry;
 use crate::image::Image;
 use crate::util::state::InputState;
-use gif::{Encoder as BaseEncoder, Frame, Repeat};
+use gif::{DisposalMethod, Encoder as BaseEncoder, Frame, Repeat};
 use image::ExtendedColorType;
 use std::convert::TryInto;
 use std::io::{self, Write};
@@ -15,7 +21,8 @@ use std::io::{self, Write};
 /* GIF encoder and settings */
 pub struct GifEncoder<'a, Output: Write> {
 	fps: u32,
-	encoder: BaseEncoder<Output>,
+	geometry: Geometry,
+	output: Output,
 	settings: &'a AnimSettings,
 }
 
@@ -27,19 +34,10 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifEncoder<'a, Output> {
 	 * @return GifEncoder (Result)
 	 */
 	fn new(config: EncoderConfig<'a, Output>) -> AppResult<Self> {
-		let mut encoder = BaseEncoder::new(
-			config.output,
-			config.geometry.width.try_into().unwrap_or_default(),
-			config.geometry.height.try_into().unwrap_or_default(),
-			&[],
-		)?;
-		encoder.set_repeat(match config.settings.repeat {
-			n if n >= 0 => Repeat::Finite(n.try_into().unwrap_or_default()),
-			_ => Repeat::Infinite,
-		})?;
 		Ok(Self {
 			fps: config.fps,
-			encoder,
+			geometry: config.geometry,
+			output: config.output,
 			settings: config.settings,
 		})
 	}
@@ -52,7 +50,7 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifEncoder<'a, Output> {
 	 * @param  Result
 	 */
 	fn save(
-		mut self,
+		self,
 		images: Vec<Image>,
 		input_state: Option<&'static InputState>,
 	) -> AppResult<()> {
@@ -62,6 +60,23 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifEncoder<'a, Output> {
 				(1., 100.),
 				(0., 29.),
 			) as i32;
+		let palette = if self.settings.gif.global_palette {
+			info!("Generating the global palette...");
+			Some(Palette::from_images(&images, &self.settings.gif, speed))
+		} else {
+			None
+		};
+		let mut encoder = BaseEncoder::new(
+			self.output,
+			self.geometry.width.try_into().unwrap_or_default(),
+			self.geometry.height.try_into().unwrap_or_default(),
+			palette.as_ref().map_or(&[], |palette| palette.get_colors()),
+		)?;
+		encoder.set_repeat(match self.settings.repeat {
+			n if n >= 0 => Repeat::Finite(n.try_into().unwrap_or_default()),
+			_ => Repeat::Infinite,
+		})?;
+		let mut pending: Option<(Frame<'static>, Vec<u8>, Geometry)> = None;
 		for (i, image) in images.iter().enumerate() {
 			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
 			info!("Saving... ({:.1}%)\r", percentage);
@@ -79,16 +94,140 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifEncoder<'a, Output> {
 					panic!("Failed to write the frames")
 				}
 			}
-			let mut frame = Frame::from_rgba_speed(
-				image.geometry.width.try_into().unwrap_or_default(),
-				image.geometry.height.try_into().unwrap_or_default(),
-				&mut image.get_data(ExtendedColorType::Rgba8),
-				speed,
+			let mut data = image.get_data(ExtendedColorType::Rgba8);
+			FrameDelta::apply_alpha_threshold(
+				&mut data,
+				self.settings.gif.alpha_threshold,
 			);
-			frame.delay = (1e2 / self.fps as f32) as u16;
-			self.encoder.write_frame(&frame)?;
+			let delta = match pending.take() {
+				Some((mut frame, previous, geometry)) => {
+					let follows = FrameDelta::can_follow(&previous, &data);
+					if follows {
+						frame.dispose = DisposalMethod::Keep;
+					} else {
+						let delay = frame.delay;
+						frame = Self::get_frame(
+							&FrameDelta::from_data(&previous, geometry),
+							palette.as_ref(),
+							&self.settings.gif,
+							speed,
+						);
+						frame.delay = delay;
+						frame.dispose = DisposalMethod::Background;
+					}
+					encoder.write_frame(&frame)?;
+					if follows {
+						FrameDelta::new(&previous, &data, image.geometry.width)
+					} else {
+						FrameDelta::from_data(&data, image.geometry)
+					}
+				}
+				None => FrameDelta::from_data(&data, image.geometry),
+			};
+			let mut frame =
+				Self::get_frame(&delta, palette.as_ref(), &self.settings.gif, speed);
+			frame.delay =
+				image.delay.map_or((1e2 / self.fps as f32) as u16, |delay| {
+					(delay / 10).try_into().unwrap_or(u16::MAX)
+				});
+			pending = Some((frame, data, image.geometry));
+		}
+		if let Some((frame, _, _)) = pending {
+			encoder.write_frame(&frame)?;
 		}
 		info!("\n");
 		Ok(())
 	}
 }
+
+impl<'a, Output: Write> GifEncoder<'a, Output> {
+	/**
+	 * Get the GIF frame that contains the pixels of the frame delta.
+	 *
+	 * @param  delta
+	 * @param  global_palette (Option)
+	 * @param  settings
+	 * @param  speed
+	 * @return Frame
+	 */
+	fn get_frame(
+		delta: &FrameDelta,
+		global_palette: Option<&Palette>,
+		settings: &GifSettings,
+		speed: i32,
+	) -> Frame<'static> {
+		let local_palette;
+		let palette = match global_palette {
+			Some(palette) => palette,
+			None => {
+				local_palette = Palette::new(
+					&delta
+						.pixels
+						.iter()
+						.flatten()
+						.flatten()
+						.copied()
+						.collect::<Vec<u8>>(),
+					settings.colors,
+					speed,
+				);
+				&local_palette
+			}
+		};
+		let mut frame = Frame::from_palette_pixels(
+			delta.area.width.try_into().unwrap_or_default(),
+			delta.area.height.try_into().unwrap_or_default(),
+			&palette.get_indices(
+				&delta.pixels,
+				delta.area.width as usize,
+				settings.dither,
+			),
+			palette.get_colors(),
+			if delta.pixels.iter().any(Option::is_none) {
+				Some(palette.transparent)
+			} else {
+				None
+			},
+		);
+		if global_palette.is_some() {
+			frame.palette = None;
+		}
+		frame.left = delta.area.x.try_into().unwrap_or_default();
+		frame.top = delta.area.y.try_into().unwrap_or_default();
+		frame
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::gif::GifDecoder;
+	use image::{AnimationDecoder, Bgra};
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_gif_transparency() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 2, 1);
+		let (red, blue) =
+			(Bgra::from([0, 0, 255, 255]), Bgra::from([255, 0, 0, 255]));
+		let images = vec![
+			Image::new(vec![red, red], true, geometry),
+			Image::new(vec![red, blue], true, geometry),
+			Image::new(vec![Bgra::from([0, 0, 0, 0]), blue], true, geometry),
+		];
+		let settings = AnimSettings::default();
+		let mut output = Vec::new();
+		GifEncoder::new(EncoderConfig::new(10, geometry, &mut output, &settings))?
+			.save(images, None)?;
+		let frames = GifDecoder::new(output.as_slice())?
+			.into_frames()
+			.collect_frames()?;
+		assert_eq!(3, frames.len());
+		assert_eq!(
+			vec![255, 0, 0, 255, 0, 0, 255, 255],
+			frames[1].buffer().to_vec()
+		);
+		assert_eq!(0, frames[2].buffer().get_pixel(0, 0)[3]);
+		assert_eq!(255, frames[2].buffer().get_pixel(1, 0)[3]);
+		Ok(())
+	}
+}
@@ -0,0 +1,260 @@
+use crate::gif::settings::{Dither, GifSettings};
+use crate::image::Image;
+use color_quant::NeuQuant;
+use image::ExtendedColorType;
+use std::collections::HashMap;
+
+/* Maximum number of pixels to sample for generating a palette */
+const MAX_SAMPLE_PIXELS: usize = 1 << 20;
+/* Threshold map for the ordered dithering */
+const BAYER_MATRIX: [[u8; 4]; 4] =
+	[[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/* Color palette with a reserved transparent index */
+pub struct Palette {
+	quantizer: Option<NeuQuant>,
+	indices: HashMap<[u8; 3], u8>,
+	colors: Vec<u8>,
+	pub transparent: u8,
+}
+
+impl Palette {
+	/**
+	 * Create a new Palette object from RGBA pixels.
+	 *
+	 * @param  pixels
+	 * @param  colors
+	 * @param  speed
+	 * @return Palette
+	 */
+	pub fn new(pixels: &[u8], colors: usize, speed: i32) -> Self {
+		let max_colors = colors.clamp(2, 256) - 1;
+		let mut indices = HashMap::new();
+		for pixel in pixels.chunks(4) {
+			let len = indices.len();
+			if len > max_colors {
+				break;
+			}
+			indices
+				.entry([pixel[0], pixel[1], pixel[2]])
+				.or_insert(len as u8);
+		}
+		let (quantizer, mut colors) = if indices.len() <= max_colors {
+			let mut colors = vec![0; indices.len() * 3];
+			for (rgb, index) in &indices {
+				let i = *index as usize * 3;
+				colors[i..i + 3].copy_from_slice(rgb);
+			}
+			(None, colors)
+		} else {
+			indices.clear();
+			let quantizer = NeuQuant::new(speed, max_colors, pixels);
+			let colors = quantizer.color_map_rgb();
+			(Some(quantizer), colors)
+		};
+		let transparent = (colors.len() / 3) as u8;
+		colors.extend(&[0, 0, 0]);
+		Self {
+			quantizer,
+			indices,
+			colors,
+			transparent,
+		}
+	}
+
+	/**
+	 * Create a Palette object from the sampled pixels of images.
+	 *
+	 * @param  images
+	 * @param  settings
+	 * @param  speed
+	 * @return Palette
+	 */
+	pub fn from_images(
+		images: &[Image],
+		settings: &GifSettings,
+		speed: i32,
+	) -> Self {
+		let total = images
+			.iter()
+			.map(|image| (image.geometry.width * image.geometry.height) as usize)
+			.sum::<usize>();
+		let stride = (total / MAX_SAMPLE_PIXELS).max(1);
+		let mut pixels = Vec::new();
+		let mut offset = 0;
+		for image in images {
+			let data = image.get_data(ExtendedColorType::Rgba8);
+			for pixel in data.chunks(4) {
+				if offset % stride == 0 && pixel[3] >= settings.alpha_threshold {
+					pixels.extend(&[pixel[0], pixel[1], pixel[2], u8::MAX]);
+				}
+				offset += 1;
+			}
+		}
+		debug!("Sampled {} pixels for the palette", pixels.len() / 4);
+		Self::new(&pixels, settings.colors, speed)
+	}
+
+	/**
+	 * Get the RGB values of the palette colors.
+	 *
+	 * @return Vector of u8
+	 */
+	pub fn get_colors(&self) -> &[u8] {
+		&self.colors
+	}
+
+	/**
+	 * Get the palette index of the closest color.
+	 *
+	 * @param  rgb
+	 * @return u8
+	 */
+	fn get_index(&self, rgb: [u8; 3]) -> u8 {
+		match (&self.quantizer, self.indices.get(&rgb)) {
+			(Some(quantizer), _) => {
+				quantizer.index_of(&[rgb[0], rgb[1], rgb[2], u8::MAX]) as u8
+			}
+			(None, Some(index)) => *index,
+			(None, None) => self
+				.colors
+				.chunks(3)
+				.take(self.transparent.into())
+				.enumerate()
+				.min_by_key(|(_, color)| {
+					color
+						.iter()
+						.zip(rgb.iter())
+						.map(|(a, b)| (*a as i32 - *b as i32).pow(2))
+						.sum::<i32>()
+				})
+				.map(|(index, _)| index as u8)
+				.unwrap_or(self.transparent),
+		}
+	}
+
+	/**
+	 * Get the palette indices of pixels using the dithering method.
+	 *
+	 * @param  pixels
+	 * @param  width
+	 * @param  dither
+	 * @return Vector of u8
+	 */
+	pub fn get_indices(
+		&self,
+		pixels: &[Option<[u8; 4]>],
+		width: usize,
+		dither: Dither,
+	) -> Vec<u8> {
+		let width = width.max(1);
+		match dither {
+			Dither::None => pixels
+				.iter()
+				.map(|pixel| match pixel {
+					Some(pixel) => self.get_index([pixel[0], pixel[1], pixel[2]]),
+					None => self.transparent,
+				})
+				.collect(),
+			Dither::Bayer => {
+				let spread = 255. / ((self.colors.len() / 3) as f32).cbrt();
+				pixels
+					.iter()
+					.enumerate()
+					.map(|(i, pixel)| match pixel {
+						Some(pixel) => {
+							let threshold =
+								BAYER_MATRIX[(i / width) % 4][(i % width) % 4];
+							let offset =
+								((threshold as f32 + 0.5) / 16. - 0.5) * spread;
+							let mut rgb = [0; 3];
+							for (c, value) in rgb.iter_mut().enumerate() {
+								*value =
+									(pixel[c] as f32 + offset).clamp(0., 255.) as u8;
+							}
+							self.get_index(rgb)
+						}
+						None => self.transparent,
+					})
+					.collect()
+			}
+			Dither::FloydSteinberg => {
+				let mut errors = vec![[0.; 3]; pixels.len()];
+				let mut indices = Vec::with_capacity(pixels.len());
+				for (i, pixel) in pixels.iter().enumerate() {
+					let pixel = match pixel {
+						Some(pixel) => pixel,
+						None => {
+							indices.push(self.transparent);
+							continue;
+						}
+					};
+					let mut rgb = [0; 3];
+					let mut color = [0.; 3];
+					for c in 0..3 {
+						color[c] = (pixel[c] as f32 + errors[i][c]).clamp(0., 255.);
+						rgb[c] = color[c] as u8;
+					}
+					let index = self.get_index(rgb);
+					indices.push(index);
+					let (x, y) = (i % width, i / width);
+					let neighbors = [
+						(x + 1 < width, i + 1, 7.),
+						(x > 0, (i + width).wrapping_sub(1), 3.),
+						(true, i + width, 5.),
+						(x + 1 < width, i + width + 1, 1.),
+					];
+					for c in 0..3 {
+						let error =
+							color[c] - self.colors[index as usize * 3 + c] as f32;
+						for (valid, j, weight) in neighbors.iter() {
+							if *valid && *j < pixels.len() && (*j / width) <= y + 1 {
+								errors[*j][c] += error * weight / 16.;
+							}
+						}
+					}
+				}
+				indices
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::Bgra;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_palette() {
+		let images = vec![Image::new(
+			vec![Bgra::from([0, 0, 0, 255]), Bgra::from([255, 255, 255, 255])],
+			false,
+			Geometry::new(0, 0, 2, 1),
+		)];
+		let palette = Palette::from_images(
+			&images,
+			&GifSettings::new(4, Dither::None, true, 128),
+			1,
+		);
+		assert_eq!(9, palette.get_colors().len());
+		assert_eq!(2, palette.transparent);
+		let pixels = vec![Some([0, 0, 0, 255]), None, Some([255, 255, 255, 255])];
+		for dither in &[Dither::None, Dither::Bayer, Dither::FloydSteinberg] {
+			let indices = palette.get_indices(&pixels, 3, *dither);
+			assert_eq!(palette.transparent, indices[1]);
+			let colors = palette.get_colors();
+			assert!(colors[indices[0] as usize * 3] < 128);
+			assert!(colors[indices[2] as usize * 3] > 128);
+		}
+		let pixels = (0..=255)
+			.flat_map(|v| vec![v, v, v, 255])
+			.collect::<Vec<u8>>();
+		let palette = Palette::new(&pixels, 16, 10);
+		assert_eq!(15, palette.transparent);
+		assert!(
+			palette.get_index([200, 200, 200]) != palette.get_index([10, 10, 10])
+		);
+	}
+}
@@ -0,0 +1,83 @@
+/* Dithering method for reducing the colors */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dither {
+	None,
+	Bayer,
+	FloydSteinberg,
+}
+
+impl Dither {
+	/**
+	 * Parse Dither from a string.
+	 *
+	 * @param  method
+	 * @return Dither
+	 */
+	pub fn parse(method: &str) -> Self {
+		match method {
+			"bayer" | "ordered" => Self::Bayer,
+			"floyd-steinberg" => Self::FloydSteinberg,
+			_ => Self::None,
+		}
+	}
+}
+
+/* GIF encoding settings */
+#[derive(Clone, Copy, Debug)]
+pub struct GifSettings {
+	pub colors: usize,
+	pub dither: Dither,
+	pub global_palette: bool,
+	pub alpha_threshold: u8,
+}
+
+/* Default initialization values for GifSettings */
+impl Default for GifSettings {
+	fn default() -> Self {
+		Self {
+			colors: 256,
+			dither: Dither::None,
+			global_palette: false,
+			alpha_threshold: 128,
+		}
+	}
+}
+
+impl GifSettings {
+	/**
+	 * Create a new GifSettings object.
+	 *
+	 * @param  colors
+	 * @param  dither
+	 * @param  global_palette
+	 * @param  alpha_threshold
+	 * @return GifSettings
+	 */
+	pub fn new(
+		colors: usize,
+		dither: Dither,
+		global_palette: bool,
+		alpha_threshold: u8,
+	) -> Self {
+		Self {
+			colors: colors.clamp(2, 256),
+			dither,
+			global_palette,
+			alpha_threshold,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_gif_settings() {
+		assert_eq!(Dither::Bayer, Dither::parse("ordered"));
+		assert_eq!(Dither::FloydSteinberg, Dither::parse("floyd-steinberg"));
+		assert_eq!(Dither::None, Dither::parse("none"));
+		assert_eq!(256, GifSettings::new(1000, Dither::None, true, 0).colors);
+		assert_eq!(2, GifSettings::new(0, Dither::None, true, 0).colors);
+	}
+}
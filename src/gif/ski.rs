@@ -56,6 +56,7 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifskiEncoder<Output> {
 		let fps = self.fps;
 		let mut collector = self.collector;
 		let collector_thread = thread::spawn(move || {
+			let mut timestamp = 0.;
 			for (i, image) in images.iter().enumerate() {
 				let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
 				info!("Saving... ({:.1}%)\r", percentage);
@@ -74,8 +75,11 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifskiEncoder<Output> {
 					}
 				}
 				collector
-					.add_frame_rgba(i, image.get_img_vec(), i as f64 / fps as f64)
+					.add_frame_rgba(i, image.get_img_vec(), timestamp)
 					.expect("Failed to collect a frame");
+				timestamp += image
+					.delay
+					.map_or(1. / fps as f64, |delay| delay as f64 / 1e3);
 			}
 			info!("\n");
 		});
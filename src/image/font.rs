@@ -0,0 +1,227 @@
+use crate::image::Image;
+use image::Bgra;
+
+/* Width and height of a glyph in pixels */
+pub const GLYPH_SIZE: (u32, u32) = (6, 10);
+
+/* Printable ASCII glyphs (misc-fixed 6x10, public domain) */
+const GLYPHS: [[u8; 10]; 95] = [
+	[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], /* ' ' */
+	[0x00, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x20, 0x00, 0x00], /* '!' */
+	[0x00, 0x50, 0x50, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], /* '"' */
+	[0x00, 0x50, 0x50, 0xF8, 0x50, 0xF8, 0x50, 0x50, 0x00, 0x00], /* '#' */
+	[0x00, 0x20, 0x70, 0xA0, 0x70, 0x28, 0x70, 0x20, 0x00, 0x00], /* '$' */
+	[0x00, 0x48, 0xA8, 0x50, 0x20, 0x50, 0xA8, 0x90, 0x00, 0x00], /* '%' */
+	[0x00, 0x40, 0xA0, 0xA0, 0x40, 0xA8, 0x90, 0x68, 0x00, 0x00], /* '&' */
+	[0x00, 0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], /* "'" */
+	[0x00, 0x10, 0x20, 0x40, 0x40, 0x40, 0x20, 0x10, 0x00, 0x00], /* '(' */
+	[0x00, 0x40, 0x20, 0x10, 0x10, 0x10, 0x20, 0x40, 0x00, 0x00], /* ')' */
+	[0x00, 0x00, 0x88, 0x50, 0xF8, 0x50, 0x88, 0x00, 0x00, 0x00], /* '*' */
+	[0x00, 0x00, 0x20, 0x20, 0xF8, 0x20, 0x20, 0x00, 0x00, 0x00], /* '+' */
+	[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x20, 0x40, 0x00], /* ',' */
+	[0x00, 0x00, 0x00, 0x00, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00], /* '-' */
+	[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x70, 0x20, 0x00], /* '.' */
+	[0x00, 0x08, 0x08, 0x10, 0x20, 0x40, 0x80, 0x80, 0x00, 0x00], /* '/' */
+	[0x00, 0x20, 0x50, 0x88, 0x88, 0x88, 0x50, 0x20, 0x00, 0x00], /* '0' */
+	[0x00, 0x20, 0x60, 0xA0, 0x20, 0x20, 0x20, 0xF8, 0x00, 0x00], /* '1' */
+	[0x00, 0x70, 0x88, 0x08, 0x30, 0x40, 0x80, 0xF8, 0x00, 0x00], /* '2' */
+	[0x00, 0xF8, 0x08, 0x10, 0x30, 0x08, 0x88, 0x70, 0x00, 0x00], /* '3' */
+	[0x00, 0x10, 0x30, 0x50, 0x90, 0xF8, 0x10, 0x10, 0x00, 0x00], /* '4' */
+	[0x00, 0xF8, 0x80, 0xB0, 0xC8, 0x08, 0x88, 0x70, 0x00, 0x00], /* '5' */
+	[0x00, 0x30, 0x40, 0x80, 0xB0, 0xC8, 0x88, 0x70, 0x00, 0x00], /* '6' */
+	[0x00, 0xF8, 0x08, 0x10, 0x10, 0x20, 0x40, 0x40, 0x00, 0x00], /* '7' */
+	[0x00, 0x70, 0x88, 0x88, 0x70, 0x88, 0x88, 0x70, 0x00, 0x00], /* '8' */
+	[0x00, 0x70, 0x88, 0x98, 0x68, 0x08, 0x10, 0x60, 0x00, 0x00], /* '9' */
+	[0x00, 0x00, 0x20, 0x70, 0x20, 0x00, 0x20, 0x70, 0x20, 0x00], /* ':' */
+	[0x00, 0x00, 0x20, 0x70, 0x20, 0x00, 0x30, 0x20, 0x40, 0x00], /* ';' */
+	[0x00, 0x08, 0x10, 0x20, 0x40, 0x20, 0x10, 0x08, 0x00, 0x00], /* '<' */
+	[0x00, 0x00, 0x00, 0xF8, 0x00, 0xF8, 0x00, 0x00, 0x00, 0x00], /* '=' */
+	[0x00, 0x40, 0x20, 0x10, 0x08, 0x10, 0x20, 0x40, 0x00, 0x00], /* '>' */
+	[0x00, 0x70, 0x88, 0x10, 0x20, 0x20, 0x00, 0x20, 0x00, 0x00], /* '?' */
+	[0x00, 0x70, 0x88, 0x98, 0xA8, 0xB0, 0x80, 0x70, 0x00, 0x00], /* '@' */
+	[0x00, 0x20, 0x50, 0x88, 0x88, 0xF8, 0x88, 0x88, 0x00, 0x00], /* 'A' */
+	[0x00, 0xF0, 0x48, 0x48, 0x70, 0x48, 0x48, 0xF0, 0x00, 0x00], /* 'B' */
+	[0x00, 0x70, 0x88, 0x80, 0x80, 0x80, 0x88, 0x70, 0x00, 0x00], /* 'C' */
+	[0x00, 0xF0, 0x48, 0x48, 0x48, 0x48, 0x48, 0xF0, 0x00, 0x00], /* 'D' */
+	[0x00, 0xF8, 0x80, 0x80, 0xF0, 0x80, 0x80, 0xF8, 0x00, 0x00], /* 'E' */
+	[0x00, 0xF8, 0x80, 0x80, 0xF0, 0x80, 0x80, 0x80, 0x00, 0x00], /* 'F' */
+	[0x00, 0x70, 0x88, 0x80, 0x80, 0x98, 0x88, 0x70, 0x00, 0x00], /* 'G' */
+	[0x00, 0x88, 0x88, 0x88, 0xF8, 0x88, 0x88, 0x88, 0x00, 0x00], /* 'H' */
+	[0x00, 0x70, 0x20, 0x20, 0x20, 0x20, 0x20, 0x70, 0x00, 0x00], /* 'I' */
+	[0x00, 0x38, 0x10, 0x10, 0x10, 0x10, 0x90, 0x60, 0x00, 0x00], /* 'J' */
+	[0x00, 0x88, 0x90, 0xA0, 0xC0, 0xA0, 0x90, 0x88, 0x00, 0x00], /* 'K' */
+	[0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xF8, 0x00, 0x00], /* 'L' */
+	[0x00, 0x88, 0x88, 0xD8, 0xA8, 0x88, 0x88, 0x88, 0x00, 0x00], /* 'M' */
+	[0x00, 0x88, 0x88, 0xC8, 0xA8, 0x98, 0x88, 0x88, 0x00, 0x00], /* 'N' */
+	[0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00], /* 'O' */
+	[0x00, 0xF0, 0x88, 0x88, 0xF0, 0x80, 0x80, 0x80, 0x00, 0x00], /* 'P' */
+	[0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0xA8, 0x70, 0x08, 0x00], /* 'Q' */
+	[0x00, 0xF0, 0x88, 0x88, 0xF0, 0xA0, 0x90, 0x88, 0x00, 0x00], /* 'R' */
+	[0x00, 0x70, 0x88, 0x80, 0x70, 0x08, 0x88, 0x70, 0x00, 0x00], /* 'S' */
+	[0x00, 0xF8, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00], /* 'T' */
+	[0x00, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00], /* 'U' */
+	[0x00, 0x88, 0x88, 0x88, 0x50, 0x50, 0x50, 0x20, 0x00, 0x00], /* 'V' */
+	[0x00, 0x88, 0x88, 0x88, 0xA8, 0xA8, 0xD8, 0x88, 0x00, 0x00], /* 'W' */
+	[0x00, 0x88, 0x88, 0x50, 0x20, 0x50, 0x88, 0x88, 0x00, 0x00], /* 'X' */
+	[0x00, 0x88, 0x88, 0x50, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00], /* 'Y' */
+	[0x00, 0xF8, 0x08, 0x10, 0x20, 0x40, 0x80, 0xF8, 0x00, 0x00], /* 'Z' */
+	[0x00, 0x70, 0x40, 0x40, 0x40, 0x40, 0x40, 0x70, 0x00, 0x00], /* '[' */
+	[0x00, 0x80, 0x80, 0x40, 0x20, 0x10, 0x08, 0x08, 0x00, 0x00], /* '\\' */
+	[0x00, 0x70, 0x10, 0x10, 0x10, 0x10, 0x10, 0x70, 0x00, 0x00], /* ']' */
+	[0x00, 0x20, 0x50, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], /* '^' */
+	[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF8, 0x00], /* '_' */
+	[0x20, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], /* '`' */
+	[0x00, 0x00, 0x00, 0x70, 0x08, 0x78, 0x88, 0x78, 0x00, 0x00], /* 'a' */
+	[0x00, 0x80, 0x80, 0xB0, 0xC8, 0x88, 0xC8, 0xB0, 0x00, 0x00], /* 'b' */
+	[0x00, 0x00, 0x00, 0x70, 0x88, 0x80, 0x88, 0x70, 0x00, 0x00], /* 'c' */
+	[0x00, 0x08, 0x08, 0x68, 0x98, 0x88, 0x98, 0x68, 0x00, 0x00], /* 'd' */
+	[0x00, 0x00, 0x00, 0x70, 0x88, 0xF8, 0x80, 0x70, 0x00, 0x00], /* 'e' */
+	[0x00, 0x30, 0x48, 0x40, 0xF0, 0x40, 0x40, 0x40, 0x00, 0x00], /* 'f' */
+	[0x00, 0x00, 0x00, 0x78, 0x88, 0x88, 0x78, 0x08, 0x88, 0x70], /* 'g' */
+	[0x00, 0x80, 0x80, 0xB0, 0xC8, 0x88, 0x88, 0x88, 0x00, 0x00], /* 'h' */
+	[0x00, 0x20, 0x00, 0x60, 0x20, 0x20, 0x20, 0x70, 0x00, 0x00], /* 'i' */
+	[0x00, 0x08, 0x00, 0x18, 0x08, 0x08, 0x08, 0x48, 0x48, 0x30], /* 'j' */
+	[0x00, 0x80, 0x80, 0x88, 0x90, 0xE0, 0x90, 0x88, 0x00, 0x00], /* 'k' */
+	[0x00, 0x60, 0x20, 0x20, 0x20, 0x20, 0x20, 0x70, 0x00, 0x00], /* 'l' */
+	[0x00, 0x00, 0x00, 0xD0, 0xA8, 0xA8, 0xA8, 0x88, 0x00, 0x00], /* 'm' */
+	[0x00, 0x00, 0x00, 0xB0, 0xC8, 0x88, 0x88, 0x88, 0x00, 0x00], /* 'n' */
+	[0x00, 0x00, 0x00, 0x70, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00], /* 'o' */
+	[0x00, 0x00, 0x00, 0xB0, 0xC8, 0x88, 0xC8, 0xB0, 0x80, 0x80], /* 'p' */
+	[0x00, 0x00, 0x00, 0x68, 0x98, 0x88, 0x98, 0x68, 0x08, 0x08], /* 'q' */
+	[0x00, 0x00, 0x00, 0xB0, 0xC8, 0x80, 0x80, 0x80, 0x00, 0x00], /* 'r' */
+	[0x00, 0x00, 0x00, 0x70, 0x80, 0x70, 0x08, 0xF0, 0x00, 0x00], /* 's' */
+	[0x00, 0x40, 0x40, 0xF0, 0x40, 0x40, 0x48, 0x30, 0x00, 0x00], /* 't' */
+	[0x00, 0x00, 0x00, 0x88, 0x88, 0x88, 0x98, 0x68, 0x00, 0x00], /* 'u' */
+	[0x00, 0x00, 0x00, 0x88, 0x88, 0x50, 0x50, 0x20, 0x00, 0x00], /* 'v' */
+	[0x00, 0x00, 0x00, 0x88, 0x88, 0xA8, 0xA8, 0x50, 0x00, 0x00], /* 'w' */
+	[0x00, 0x00, 0x00, 0x88, 0x50, 0x20, 0x50, 0x88, 0x00, 0x00], /* 'x' */
+	[0x00, 0x00, 0x00, 0x88, 0x88, 0x98, 0x68, 0x08, 0x88, 0x70], /* 'y' */
+	[0x00, 0x00, 0x00, 0xF8, 0x10, 0x20, 0x40, 0xF8, 0x00, 0x00], /* 'z' */
+	[0x00, 0x18, 0x20, 0x10, 0x60, 0x10, 0x20, 0x18, 0x00, 0x00], /* '{' */
+	[0x00, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00], /* '|' */
+	[0x00, 0x60, 0x10, 0x20, 0x18, 0x20, 0x10, 0x60, 0x00, 0x00], /* '}' */
+	[0x00, 0x48, 0xA8, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], /* '~' */
+];
+
+/* Fixed width bitmap font for drawing text on images */
+#[derive(Clone, Copy, Debug)]
+pub struct BitmapFont {
+	scale: u32,
+}
+
+/* Default initialization values for BitmapFont */
+impl Default for BitmapFont {
+	fn default() -> Self {
+		Self { scale: 1 }
+	}
+}
+
+impl BitmapFont {
+	/**
+	 * Create a new BitmapFont object.
+	 *
+	 * @param  scale
+	 * @return BitmapFont
+	 */
+	pub fn new(scale: u32) -> Self {
+		Self {
+			scale: scale.max(1),
+		}
+	}
+
+	/**
+	 * Get the glyph of the given character.
+	 *
+	 * @param  c
+	 * @return Glyph
+	 */
+	fn get_glyph(c: char) -> &'static [u8; 10] {
+		match c {
+			' '..='~' => &GLYPHS[c as usize - 0x20],
+			_ => &GLYPHS['?' as usize - 0x20],
+		}
+	}
+
+	/**
+	 * Get the size of the given text in pixels.
+	 *
+	 * @param  text
+	 * @return Tuple of width and height
+	 */
+	pub fn get_text_size(&self, text: &str) -> (u32, u32) {
+		(
+			text.chars().count() as u32 * GLYPH_SIZE.0 * self.scale,
+			GLYPH_SIZE.1 * self.scale,
+		)
+	}
+
+	/**
+	 * Draw the given text on the image.
+	 *
+	 * @param  image
+	 * @param  text
+	 * @param  position
+	 * @param  color
+	 * @param  opacity
+	 */
+	pub fn draw(
+		&self,
+		image: &mut Image,
+		text: &str,
+		position: (i32, i32),
+		color: Bgra<u8>,
+		opacity: f32,
+	) {
+		let scale = self.scale as i32;
+		for (i, c) in text.chars().enumerate() {
+			let origin = position.0 + (i as u32 * GLYPH_SIZE.0) as i32 * scale;
+			for (row, bits) in Self::get_glyph(c).iter().enumerate() {
+				for column in 0..GLYPH_SIZE.0 {
+					if bits & (0x80 >> column) == 0 {
+						continue;
+					}
+					for dy in 0..scale {
+						for dx in 0..scale {
+							image.blend_pixel(
+								origin + column as i32 * scale + dx,
+								position.1 + row as i32 * scale + dy,
+								color,
+								opacity,
+							);
+						}
+					}
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::ExtendedColorType;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_bitmap_font() {
+		let font = BitmapFont::new(2);
+		assert_eq!((48, 20), font.get_text_size("Test"));
+		assert_eq!(
+			BitmapFont::get_glyph('?'),
+			BitmapFont::get_glyph('\u{221E}')
+		);
+		let geometry = Geometry::new(0, 0, 6, 10);
+		let black = Bgra::from([0, 0, 0, 255]);
+		let white = Bgra::from([255, 255, 255, 255]);
+		let mut image = Image::new(vec![black; 60], false, geometry);
+		BitmapFont::default().draw(&mut image, "|", (0, 0), white, 1.);
+		let pixels = image
+			.get_data(ExtendedColorType::Rgb8)
+			.chunks(3)
+			.enumerate()
+			.filter(|(_, v)| v == &[255, 255, 255])
+			.map(|(i, _)| i)
+			.collect::<Vec<usize>>();
+		assert_eq!(vec![8, 14, 20, 26, 32, 38, 44], pixels);
+	}
+}
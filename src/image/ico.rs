@@ -0,0 +1,147 @@
+use crate::app::AppResult;
+use crate::image::settings::IcoSettings;
+use crate::image::Image;
+use image::codecs::png::PngEncoder;
+use image::imageops;
+use image::{ColorType, ExtendedColorType, RgbaImage};
+use std::io::Write;
+
+/* Sizes of the ICO header and directory entries */
+const HEADER_SIZE: u32 = 6;
+const ENTRY_SIZE: u32 = 16;
+
+/* ICO encoder that embeds the image in multiple sizes */
+pub struct IcoEncoder<Output: Write> {
+	output: Output,
+	settings: IcoSettings,
+}
+
+impl<Output: Write> IcoEncoder<Output> {
+	/**
+	 * Create a new IcoEncoder object.
+	 *
+	 * @param  output
+	 * @param  settings
+	 * @return IcoEncoder
+	 */
+	pub fn new(output: Output, settings: IcoSettings) -> Self {
+		Self { output, settings }
+	}
+
+	/**
+	 * Encode the image in the icon sizes and write to the ICO file.
+	 *
+	 * @param  image
+	 * @return Result
+	 */
+	pub fn encode(self, image: &Image) -> AppResult<()> {
+		let icons = self.get_icons(image)?;
+		self.write(&icons)
+	}
+
+	/**
+	 * Get the PNG encoded icons in the specified sizes.
+	 * Images are scaled to fit and centered on a transparent square.
+	 *
+	 * @param  image
+	 * @return Vector of the size and data (Result)
+	 */
+	pub fn get_icons(&self, image: &Image) -> AppResult<Vec<(u32, Vec<u8>)>> {
+		let (width, height) = (image.geometry.width, image.geometry.height);
+		let buffer = RgbaImage::from_raw(
+			width,
+			height,
+			image.get_data(ExtendedColorType::Rgba8),
+		)
+		.unwrap_or_default();
+		let mut icons = Vec::new();
+		for size in &self.settings.sizes {
+			let scale = *size as f32 / width.max(height).max(1) as f32;
+			let resized = imageops::resize(
+				&buffer,
+				((width as f32 * scale).round() as u32).clamp(1, *size),
+				((height as f32 * scale).round() as u32).clamp(1, *size),
+				self.settings.filter,
+			);
+			let mut icon = RgbaImage::new(*size, *size);
+			imageops::overlay(
+				&mut icon,
+				&resized,
+				(size - resized.width()) / 2,
+				(size - resized.height()) / 2,
+			);
+			let mut data = Vec::new();
+			PngEncoder::new(&mut data).encode(
+				&icon.into_raw(),
+				*size,
+				*size,
+				ColorType::Rgba8,
+			)?;
+			icons.push((*size, data));
+		}
+		Ok(icons)
+	}
+
+	/**
+	 * Write the icons to the output with the ICO header and directory.
+	 *
+	 * @param  icons
+	 * @return Result
+	 */
+	pub fn write(mut self, icons: &[(u32, Vec<u8>)]) -> AppResult<()> {
+		self.output.write_all(&[0, 0, 1, 0])?;
+		self.output.write_all(&(icons.len() as u16).to_le_bytes())?;
+		let mut offset = HEADER_SIZE + ENTRY_SIZE * icons.len() as u32;
+		for (size, data) in icons {
+			let size = if *size >= 256 { 0 } else { *size as u8 };
+			self.output.write_all(&[size, size, 0, 0, 1, 0, 32, 0])?;
+			self.output.write_all(&(data.len() as u32).to_le_bytes())?;
+			self.output.write_all(&offset.to_le_bytes())?;
+			offset += data.len() as u32;
+		}
+		for (_, data) in icons {
+			self.output.write_all(data)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::ico::IcoDecoder;
+	use image::{Bgra, ImageDecoder};
+	use pretty_assertions::assert_eq;
+	use std::io::Cursor;
+	#[test]
+	fn test_ico_encoder() -> AppResult<()> {
+		let image = Image::new(
+			vec![Bgra::from([0, 0, 255, 255]); 40 * 20],
+			false,
+			Geometry::new(0, 0, 40, 20),
+		);
+		let settings = IcoSettings {
+			sizes: vec![16, 256],
+			..IcoSettings::default()
+		};
+		let encoder = IcoEncoder::new(Vec::new(), settings.clone());
+		let icons = encoder.get_icons(&image)?;
+		assert_eq!(
+			vec![16, 256],
+			icons.iter().map(|v| v.0).collect::<Vec<u32>>()
+		);
+		let icon = image::load_from_memory(&icons[0].1)?.into_rgba8();
+		assert_eq!(0, icon.get_pixel(8, 1)[3]);
+		assert_eq!(&[255, 0, 0, 255], &icon.get_pixel(8, 8).0);
+		let mut output = Vec::new();
+		IcoEncoder::new(&mut output, settings).encode(&image)?;
+		assert_eq!(2, u16::from_le_bytes([output[4], output[5]]));
+		assert_eq!(0, output[6 + ENTRY_SIZE as usize]);
+		assert_eq!(
+			(256, 256),
+			IcoDecoder::new(Cursor::new(output))?.dimensions()
+		);
+		Ok(())
+	}
+}
@@ -1,10 +1,13 @@
+pub mod font;
 pub mod geometry;
+pub mod ico;
 pub mod padding;
 pub mod settings;
 use std::fmt;
 
 use crate::image::geometry::Geometry;
-use image::{Bgra, ExtendedColorType};
+use image::imageops::{self, FilterType};
+use image::{Bgra, ExtendedColorType, RgbaImage};
 #[cfg(feature = "ski")]
 use {
 	imgref::{Img, ImgVec},
@@ -21,6 +24,8 @@ pub struct Image {
 	data: Vec<Bgra<u8>>,
 	alpha_channel: bool,
 	pub geometry: Geometry,
+	pub delay: Option<u32>,
+	pub marker: bool,
 }
 
 /* Debug implementation for programmer-facing output */
@@ -52,6 +57,144 @@ impl Image {
 			data,
 			alpha_channel,
 			geometry,
+			delay: None,
+			marker: false,
+		}
+	}
+
+	/**
+	 * Compose the given images side by side into a single image.
+	 *
+	 * @param  images
+	 * @return Image
+	 */
+	pub fn compose(images: &[&Image]) -> Self {
+		let width = images.iter().map(|image| image.geometry.width).sum::<u32>();
+		let height = images
+			.iter()
+			.map(|image| image.geometry.height)
+			.max()
+			.unwrap_or_default();
+		let mut data = vec![Bgra::from([0, 0, 0, 255]); (width * height) as usize];
+		let mut offset = 0;
+		for image in images {
+			let image_width = image.geometry.width as usize;
+			for (y, row) in image.data.chunks(image_width.max(1)).enumerate() {
+				let start = y * width as usize + offset;
+				data[start..start + row.len()].copy_from_slice(row);
+			}
+			offset += image_width;
+		}
+		let mut image = Self::new(
+			data,
+			images.iter().any(|image| image.alpha_channel),
+			Geometry::new(0, 0, width, height),
+		);
+		image.marker = images.iter().any(|image| image.marker);
+		image
+	}
+
+	/**
+	 * Get the percentage of pixels that differ from the given image.
+	 *
+	 * @param  image
+	 * @return f64
+	 */
+	pub fn get_difference(&self, image: &Image) -> f64 {
+		if self.geometry != image.geometry || self.data.len() != image.data.len() {
+			return 100.;
+		} else if self.data.is_empty() {
+			return 0.;
+		}
+		let channels = if self.alpha_channel || image.alpha_channel {
+			4
+		} else {
+			3
+		};
+		let changed = self
+			.data
+			.iter()
+			.zip(image.data.iter())
+			.filter(|(a, b)| a.0[..channels] != b.0[..channels])
+			.count();
+		(changed as f64 / self.data.len() as f64) * 100.
+	}
+
+	/**
+	 * Blend the pixel at the given position with a color.
+	 *
+	 * @param  x
+	 * @param  y
+	 * @param  color
+	 * @param  opacity
+	 */
+	pub fn blend_pixel(&mut self, x: i32, y: i32, color: Bgra<u8>, opacity: f32) {
+		if x < 0
+			|| y < 0 || x as u32 >= self.geometry.width
+			|| y as u32 >= self.geometry.height
+		{
+			return;
+		}
+		let opacity = opacity.clamp(0., 1.);
+		if let Some(pixel) = self
+			.data
+			.get_mut((y as u32 * self.geometry.width + x as u32) as usize)
+		{
+			for i in 0..3 {
+				pixel[i] = (pixel[i] as f32 * (1. - opacity)
+					+ color[i] as f32 * opacity)
+					.round() as u8;
+			}
+			pixel[3] = pixel[3].max((opacity * 255.) as u8);
+		}
+	}
+
+	/**
+	 * Fill the given area of the image with a color.
+	 *
+	 * @param  area
+	 * @param  color
+	 * @param  opacity
+	 */
+	pub fn fill_rect(&mut self, area: Geometry, color: Bgra<u8>, opacity: f32) {
+		for y in area.y..area.y + area.height as i32 {
+			for x in area.x..area.x + area.width as i32 {
+				self.blend_pixel(x, y, color, opacity);
+			}
+		}
+	}
+
+	/**
+	 * Resize the image with the given ratio.
+	 *
+	 * @param  ratio
+	 * @param  filter
+	 * @return Image
+	 */
+	pub fn resize(&self, ratio: f32, filter: FilterType) -> Self {
+		if ratio <= 0. || (ratio - 1.).abs() < f32::EPSILON {
+			return self.clone();
+		}
+		let (width, height) = (
+			((self.geometry.width as f32 * ratio) as u32).max(1),
+			((self.geometry.height as f32 * ratio) as u32).max(1),
+		);
+		let buffer = RgbaImage::from_raw(
+			self.geometry.width,
+			self.geometry.height,
+			self.get_data(ExtendedColorType::Rgba8),
+		)
+		.unwrap_or_default();
+		Self {
+			data: imageops::resize(&buffer, width, height, filter)
+				.into_vec()
+				.chunks(4)
+				.map(|rgba| Bgra::from([rgba[2], rgba[1], rgba[0], rgba[3]]))
+				.collect(),
+			alpha_channel: self.alpha_channel,
+			geometry: Geometry::new(0, 0, width, height),
+			delay: self.delay,
+			marker: self.marker,
 		}
 	}
 
@@ -139,5 +282,33 @@ mod tests {
 		assert_eq!(255, image.get_data(ExtendedColorType::Rgb8)[4]);
 		assert_eq!(255, image.get_data(ExtendedColorType::Rgba8)[5]);
 		assert_eq!(128, image.get_data(ExtendedColorType::Rgba16)[5]);
+		assert_eq!(0., image.get_difference(&image.clone()));
+		let other =
+			Image::new(vec![data[0], Bgra::from([0, 0, 0, 0])], false, geometry);
+		assert_eq!(50., image.get_difference(&other));
+		assert_eq!(
+			100.,
+			image.get_difference(&Image::new(Vec::new(), false, geometry))
+		);
+		let composed = Image::compose(&[
+			&Image::new(vec![data[1]; 4], false, Geometry::new(0, 0, 2, 2)),
+			&Image::new(vec![data[0]], false, Geometry::new(0, 0, 1, 1)),
+		]);
+		assert_eq!(Geometry::new(0, 0, 3, 2), composed.geometry);
+		assert_eq!(
+			vec![255, 255, 128, 255, 255, 0],
+			composed.get_data(ExtendedColorType::L8)
+		);
+		assert!(!composed.marker);
+		let mut marked = Image::new(vec![data[0]], false, Geometry::new(0, 0, 1, 1));
+		marked.marker = true;
+		assert!(Image::compose(&[&image, &marked]).marker);
+		let mut image =
+			Image::new(vec![data[1]; 16], false, Geometry::new(0, 0, 4, 4));
+		image.delay = Some(50);
+		let resized = image.resize(0.5, FilterType::Triangle);
+		assert_eq!(Geometry::new(0, 0, 2, 2), resized.geometry);
+		assert_eq!(Some(50), resized.delay);
+		assert_eq!(vec![255; 4], resized.get_data(ExtendedColorType::L8));
 	}
 }
@@ -2,7 +2,9 @@ use crate::args::matches::ArgMatches;
 use crate::args::parser::ArgParser;
 use image::codecs::png::CompressionType;
 use image::codecs::png::FilterType;
+use image::imageops::FilterType as SamplingFilter;
 use image::pnm::{PnmSubtype, SampleEncoding};
+use std::path::PathBuf;
 
 /* PNG compression and filter settings */
 #[derive(Clone, Copy, Debug)]
@@ -52,7 +54,7 @@ impl PngSettings {
 	 * @param  parser
 	 * @return PngSettings
 	 */
-	fn from_parser(parser: ArgParser<'_>) -> Self {
+	pub fn from_parser(parser: ArgParser<'_>) -> Self {
 		match parser.args {
 			Some(matches) => Self::new(
 				match matches.value_of("compression") {
@@ -117,7 +119,7 @@ impl JpgSettings {
 	 * @param  parser
 	 * @return JpgSettings
 	 */
-	fn from_parser(parser: ArgParser<'_>) -> Self {
+	pub fn from_parser(parser: ArgParser<'_>) -> Self {
 		match parser.args {
 			Some(_) => Self::new(parser.parse("quality", Self::default().quality)),
 			None => Self::default(),
@@ -186,6 +188,102 @@ impl PnmSettings {
 	}
 }
 
+/* ICO sizes and favicon settings */
+#[derive(Clone, Debug)]
+pub struct IcoSettings {
+	pub sizes: Vec<u32>,
+	pub filter: SamplingFilter,
+	pub favicon_set: Option<PathBuf>,
+}
+
+/* Default initialization values for IcoSettings */
+impl Default for IcoSettings {
+	fn default() -> Self {
+		Self {
+			sizes: vec![16, 24, 32, 48, 64, 128, 256],
+			filter: SamplingFilter::Lanczos3,
+			favicon_set: None,
+		}
+	}
+}
+
+impl IcoSettings {
+	/**
+	 * Create a new IcoSettings object.
+	 *
+	 * @param  sizes
+	 * @param  filter
+	 * @param  favicon_set (Option)
+	 * @return IcoSettings
+	 */
+	pub fn new(
+		sizes: Vec<u32>,
+		filter: SamplingFilter,
+		favicon_set: Option<PathBuf>,
+	) -> Self {
+		Self {
+			sizes,
+			filter,
+			favicon_set,
+		}
+	}
+
+	/**
+	 * Create a new IcoSettings object from arguments.
+	 *
+	 * @param  matches
+	 * @return IcoSettings
+	 */
+	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(matches, "ico"))
+	}
+
+	/**
+	 * Create a IcoSettings object from an argument parser.
+	 *
+	 * @param  parser
+	 * @return IcoSettings
+	 */
+	fn from_parser(parser: ArgParser<'_>) -> Self {
+		match parser.args {
+			Some(matches) => Self::new(
+				Self::parse_sizes(matches.value_of("sizes").unwrap_or_default())
+					.unwrap_or_else(|| Self::default().sizes),
+				match matches.value_of("filter") {
+					Some("nearest") => SamplingFilter::Nearest,
+					Some("triangle") => SamplingFilter::Triangle,
+					Some("catmull-rom") => SamplingFilter::CatmullRom,
+					Some("gaussian") => SamplingFilter::Gaussian,
+					_ => SamplingFilter::Lanczos3,
+				},
+				matches.value_of("favicon-set").map(PathBuf::from),
+			),
+			None => Self::default(),
+		}
+	}
+
+	/**
+	 * Parse the comma separated icon sizes.
+	 *
+	 * @param  sizes
+	 * @return Vector of u32 (Option)
+	 */
+	fn parse_sizes(sizes: &str) -> Option<Vec<u32>> {
+		let mut sizes = sizes
+			.split(',')
+			.filter_map(|size| size.trim().parse::<u32>().ok())
+			.filter(|size| (1..=256).contains(size))
+			.collect::<Vec<u32>>();
+		sizes.sort_unstable();
+		sizes.dedup();
+		if sizes.is_empty() {
+			None
+		} else {
+			Some(sizes)
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -266,4 +364,29 @@ mod tests {
 			PnmSettings::from_parser(ArgParser::new(None)).subtype
 		)
 	}
+	#[test]
+	fn test_ico_settings() {
+		let args = App::new("test")
+			.arg(Arg::with_name("sizes").long("sizes").takes_value(true))
+			.arg(Arg::with_name("filter").long("filter").takes_value(true))
+			.arg(
+				Arg::with_name("favicon-set")
+					.long("favicon-set")
+					.takes_value(true),
+			)
+			.get_matches_from(vec![
+				"test",
+				"--sizes",
+				"48, 16,512,16,x",
+				"--filter",
+				"nearest",
+				"--favicon-set",
+				"icons",
+			]);
+		let ico_settings = IcoSettings::from_parser(ArgParser::from_args(&args));
+		assert_eq!(vec![16, 48], ico_settings.sizes);
+		assert_eq!(SamplingFilter::Nearest, ico_settings.filter);
+		assert_eq!(Some(PathBuf::from("icons")), ico_settings.favicon_set);
+		assert_eq!(None, IcoSettings::parse_sizes("0,300"));
+	}
 }
@@ -10,19 +10,26 @@ mod anim;
 mod apng;
 mod app;
 mod args;
+mod avi;
 mod edit;
+mod exec;
 mod file;
 mod gif;
 mod image;
+mod pdf;
 mod record;
 mod settings;
+mod tiff;
+mod tty;
 mod util;
 mod view;
+mod webp;
 mod window;
 #[cfg(not(all(unix, not(target_os = "macos"))))]
 mod ws;
 #[cfg(all(unix, not(target_os = "macos")))]
 mod x11;
+mod y4m;
 use self::app::App;
 use self::args::matches::ArgMatches;
 use self::args::Args;
@@ -32,6 +39,8 @@ use self::window::Access;
 #[cfg(not(all(unix, not(target_os = "macos"))))]
 use self::ws::WindowSystem;
 #[cfg(all(unix, not(target_os = "macos")))]
+use self::x11::xvfb::VirtualDisplay;
+#[cfg(all(unix, not(target_os = "macos")))]
 use self::x11::WindowSystem;
 
 fn main() {
@@ -42,15 +51,36 @@ fn main() {
 		.init()
 		.expect("Failed to initialize the logger");
 	settings.check();
+	#[cfg(all(unix, not(target_os = "macos")))]
+	let _virtual_display = match settings.record.virtual_size {
+		Some(size) => match VirtualDisplay::start(size) {
+			Ok(display) => Some(display),
+			Err(e) => {
+				error!("Failed to start the virtual display: {}", e);
+				return;
+			}
+		},
+		None => None,
+	};
+	let mut windows = Vec::new();
 	let window = if settings.window_required {
 		match WindowSystem::init(&settings) {
-			Some(mut ws) => match ws.get_window() {
-				Some(window) => Some(window),
-				None => {
-					error!("Failed to retrieve the window.");
-					return;
+			Some(mut ws) => {
+				if settings.multi.is_enabled() {
+					windows = ws.get_windows(&settings.multi.windows);
+					if windows.len() != settings.multi.windows.len() {
+						error!("Failed to retrieve the windows.");
+						return;
+					}
+				}
+				match windows.first().copied().or_else(|| ws.get_window()) {
+					Some(window) => Some(window),
+					None => {
+						error!("Failed to retrieve the window.");
+						return;
+					}
 				}
-			},
+			}
 			None => {
 				error!("Failed to access the window system.");
 				return;
@@ -59,7 +89,7 @@ fn main() {
 	} else {
 		None
 	};
-	if let Err(e) = App::new(window, &settings).start() {
+	if let Err(e) = App::new(window, &settings).with_windows(&windows).start() {
 		error!("{}", e);
 	}
 }
@@ -0,0 +1,241 @@
+pub mod settings;
+
+use crate::anim::settings::AnimSettings;
+use crate::app::AppResult;
+use crate::image::Image;
+use crate::util::state::InputState;
+use image::jpeg::JpegEncoder;
+use image::{ColorType, ExtendedColorType};
+use miniz_oxide::deflate::compress_to_vec_zlib;
+use std::io::{self, Write};
+
+/* PDF encoder that writes the images as pages */
+pub struct PdfEncoder<'a, Output: Write> {
+	output: Output,
+	settings: &'a AnimSettings,
+	offset: usize,
+	objects: Vec<usize>,
+}
+
+impl<'a, Output: Write> PdfEncoder<'a, Output> {
+	/**
+	 * Create a new PdfEncoder object.
+	 *
+	 * @param  output
+	 * @param  settings
+	 * @return PdfEncoder
+	 */
+	pub fn new(output: Output, settings: &'a AnimSettings) -> Self {
+		Self {
+			output,
+			settings,
+			offset: 0,
+			objects: Vec::new(),
+		}
+	}
+
+	/**
+	 * Encode images as pages and write to the PDF file.
+	 *
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	pub fn save(
+		mut self,
+		images: Vec<Image>,
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		self.write(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n")?;
+		self.write_object("<< /Type /Catalog /Pages 2 0 R >>", None)?;
+		let kids = (0..images.len())
+			.map(|i| format!("{} 0 R", 3 + i * 3))
+			.collect::<Vec<String>>();
+		self.write_object(
+			&format!(
+				"<< /Type /Pages /Kids [{}] /Count {} >>",
+				kids.join(" "),
+				images.len()
+			),
+			None,
+		)?;
+		for (i, image) in images.iter().enumerate() {
+			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
+			info!("Saving... ({:.1}%)\r", percentage);
+			debug!(
+				"Encoding... ({:.1}%) [{}/{}]\r",
+				percentage,
+				i + 1,
+				images.len()
+			);
+			io::stdout().flush()?;
+			if let Some(state) = input_state {
+				if state.check_cancel_keys() {
+					info!("\n");
+					warn!("User interrupt detected.");
+					panic!("Failed to write the frames")
+				}
+			}
+			self.write_page(image)?;
+		}
+		self.write_trailer()?;
+		info!("\n");
+		Ok(())
+	}
+
+	/**
+	 * Write the page, content stream and image objects.
+	 *
+	 * @param  image
+	 * @return Result
+	 */
+	fn write_page(&mut self, image: &Image) -> AppResult<()> {
+		let (width, height) = (image.geometry.width, image.geometry.height);
+		let ((page_width, page_height), area) =
+			self.settings.pdf.page_size.get_layout(width, height);
+		let id = self.objects.len() + 1;
+		self.write_object(
+			&format!(
+				"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.2} {:.2}] \
+				/Resources << /XObject << /Im0 {} 0 R >> >> /Contents {} 0 R >>",
+				page_width,
+				page_height,
+				id + 2,
+				id + 1
+			),
+			None,
+		)?;
+		let content = format!(
+			"q {:.2} 0 0 {:.2} {:.2} {:.2} cm /Im0 Do Q",
+			area[2], area[3], area[0], area[1]
+		);
+		self.write_object(
+			&format!("<< /Length {} >>", content.len()),
+			Some(content.as_bytes()),
+		)?;
+		let data = image.get_data(ExtendedColorType::Rgb8);
+		let (filter, data) = if self.settings.pdf.jpeg {
+			let mut output = Vec::new();
+			JpegEncoder::new_with_quality(&mut output, self.settings.quality)
+				.encode(&data, width, height, ColorType::Rgb8)?;
+			("DCTDecode", output)
+		} else {
+			("FlateDecode", compress_to_vec_zlib(&data, 6))
+		};
+		self.write_object(
+			&format!(
+				"<< /Type /XObject /Subtype /Image /Width {} /Height {} \
+				/ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /{} /Length {} >>",
+				width,
+				height,
+				filter,
+				data.len()
+			),
+			Some(&data),
+		)
+	}
+
+	/**
+	 * Write an indirect object with an optional stream.
+	 *
+	 * @param  dictionary
+	 * @param  stream (Option)
+	 * @return Result
+	 */
+	fn write_object(
+		&mut self,
+		dictionary: &str,
+		stream: Option<&[u8]>,
+	) -> AppResult<()> {
+		self.objects.push(self.offset);
+		self.write(
+			format!("{} 0 obj\n{}\n", self.objects.len(), dictionary).as_bytes(),
+		)?;
+		if let Some(stream) = stream {
+			self.write(b"stream\n")?;
+			self.write(stream)?;
+			self.write(b"\nendstream\n")?;
+		}
+		self.write(b"endobj\n")
+	}
+
+	/**
+	 * Write the cross-reference table and the trailer.
+	 *
+	 * @return Result
+	 */
+	fn write_trailer(&mut self) -> AppResult<()> {
+		let xref = self.offset;
+		let mut table =
+			format!("xref\n0 {}\n0000000000 65535 f \n", self.objects.len() + 1);
+		for offset in &self.objects {
+			table.push_str(&format!("{:010} 00000 n \n", offset));
+		}
+		table.push_str(&format!(
+			"trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+			self.objects.len() + 1,
+			xref
+		));
+		self.write(table.as_bytes())
+	}
+
+	/**
+	 * Write the data to the output and update the offset.
+	 *
+	 * @param  data
+	 * @return Result
+	 */
+	fn write(&mut self, data: &[u8]) -> AppResult<()> {
+		self.output.write_all(data)?;
+		self.offset += data.len();
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use crate::pdf::settings::{PageSize, PdfSettings};
+	use image::Bgra;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_pdf_encoder() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 40, 20);
+		let images = vec![
+			Image::new(vec![Bgra::from([255, 0, 0, 255]); 800], false, geometry),
+			Image::new(vec![Bgra::from([0, 0, 255, 255]); 800], false, geometry),
+		];
+		let settings = AnimSettings {
+			pdf: PdfSettings::new(PageSize::Letter, true),
+			..AnimSettings::default()
+		};
+		let mut output = Vec::new();
+		PdfEncoder::new(&mut output, &settings).save(images, None)?;
+		let text = String::from_utf8_lossy(&output).to_string();
+		assert!(text.starts_with("%PDF-1.4"));
+		assert!(text.ends_with("%%EOF\n"));
+		assert_eq!(2, text.matches("/Type /Page ").count());
+		assert_eq!(2, text.matches("/DCTDecode").count());
+		assert!(text.contains("/MediaBox [0 0 792.00 612.00]"));
+		let startxref = output
+			.windows(10)
+			.rposition(|v| v == b"startxref\n")
+			.unwrap_or_default();
+		let xref = String::from_utf8_lossy(&output[startxref + 10..])
+			.lines()
+			.next()
+			.unwrap_or_default()
+			.parse::<usize>()
+			.unwrap_or_default();
+		let table = String::from_utf8_lossy(&output[xref..]).to_string();
+		assert!(table.starts_with("xref\n0 9\n"));
+		for (i, line) in table.lines().skip(3).take(8).enumerate() {
+			let offset = line[..10].parse::<usize>().unwrap_or_default();
+			assert!(
+				output[offset..].starts_with(format!("{} 0 obj", i + 1).as_bytes())
+			);
+		}
+		Ok(())
+	}
+}
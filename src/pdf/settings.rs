@@ -0,0 +1,113 @@
+/* Scale for converting pixels to points (96 DPI) */
+const PX_TO_PT: f32 = 0.75;
+/* Margin of the fixed page sizes in points */
+const PAGE_MARGIN: f32 = 36.;
+
+/* PDF page sizes */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PageSize {
+	Fit,
+	A4,
+	Letter,
+}
+
+impl PageSize {
+	/**
+	 * Parse PageSize from a string.
+	 *
+	 * @param  page_size
+	 * @return PageSize
+	 */
+	pub fn parse(page_size: &str) -> Self {
+		match page_size {
+			"a4" => Self::A4,
+			"letter" => Self::Letter,
+			_ => Self::Fit,
+		}
+	}
+
+	/**
+	 * Get the page size and the area of the image on the page.
+	 * Fixed page sizes are rotated to landscape for wide images.
+	 *
+	 * @param  width
+	 * @param  height
+	 * @return Tuple of page size and image area (in points)
+	 */
+	pub fn get_layout(&self, width: u32, height: u32) -> ((f32, f32), [f32; 4]) {
+		let (width, height) = (width as f32 * PX_TO_PT, height as f32 * PX_TO_PT);
+		let (page_width, page_height) = match self {
+			Self::Fit => return ((width, height), [0., 0., width, height]),
+			Self::A4 => (595.28, 841.89),
+			Self::Letter => (612., 792.),
+		};
+		let (page_width, page_height) = if width > height {
+			(page_height, page_width)
+		} else {
+			(page_width, page_height)
+		};
+		let scale = ((page_width - 2. * PAGE_MARGIN) / width)
+			.min((page_height - 2. * PAGE_MARGIN) / height)
+			.min(1.);
+		let (width, height) = (width * scale, height * scale);
+		(
+			(page_width, page_height),
+			[
+				(page_width - width) / 2.,
+				(page_height - height) / 2.,
+				width,
+				height,
+			],
+		)
+	}
+}
+
+/* PDF page settings */
+#[derive(Clone, Copy, Debug)]
+pub struct PdfSettings {
+	pub page_size: PageSize,
+	pub jpeg: bool,
+}
+
+/* Default initialization values for PdfSettings */
+impl Default for PdfSettings {
+	fn default() -> Self {
+		Self {
+			page_size: PageSize::Fit,
+			jpeg: false,
+		}
+	}
+}
+
+impl PdfSettings {
+	/**
+	 * Create a new PdfSettings object.
+	 *
+	 * @param  page_size
+	 * @param  jpeg
+	 * @return PdfSettings
+	 */
+	pub fn new(page_size: PageSize, jpeg: bool) -> Self {
+		Self { page_size, jpeg }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_page_size() {
+		assert_eq!(
+			((300., 150.), [0., 0., 300., 150.]),
+			PageSize::parse("fit").get_layout(400, 200)
+		);
+		assert_eq!(
+			((792., 612.), [246., 231., 300., 150.]),
+			PageSize::parse("letter").get_layout(400, 200)
+		);
+		let (page, area) = PageSize::parse("a4").get_layout(1000, 4000);
+		assert_eq!((595.28, 841.89), page);
+		assert_eq!(841.89 - 72., area[3]);
+	}
+}
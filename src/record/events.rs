@@ -0,0 +1,139 @@
+use crate::util::state::InputState;
+use device_query::DeviceQuery;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+
+/* Input events that occurred while recording a frame */
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameEvent {
+	pub frame: usize,
+	pub time: u128,
+	pub pointer: (i32, i32),
+	pub buttons: Vec<bool>,
+	pub keys: Vec<String>,
+}
+
+/* Display implementation for writing the event as JSON */
+impl fmt::Display for FrameEvent {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{{\"frame\":{},\"time\":{},\"x\":{},\"y\":{},\
+			\"buttons\":{},\"keys\":{}}}",
+			self.frame,
+			self.time,
+			self.pointer.0,
+			self.pointer.1,
+			serde_json::to_string(&self.buttons).map_err(|_| fmt::Error)?,
+			serde_json::to_string(&self.keys).map_err(|_| fmt::Error)?
+		)
+	}
+}
+
+/* Writer for the input events of recorded frames */
+pub struct EventLog {
+	output: BufWriter<File>,
+	state: Option<&'static InputState>,
+	start: Option<Instant>,
+	frame: usize,
+}
+
+impl EventLog {
+	/**
+	 * Create a new EventLog object.
+	 *
+	 * @param  path
+	 * @param  state (Option)
+	 * @return EventLog (Result)
+	 */
+	pub fn new(path: &Path, state: Option<&'static InputState>) -> io::Result<Self> {
+		let mut output = BufWriter::new(File::create(path)?);
+		output.write_all(b"[")?;
+		Ok(Self {
+			output,
+			state,
+			start: None,
+			frame: 0,
+		})
+	}
+
+	/**
+	 * Write the current input state for the next frame.
+	 *
+	 * @param  origin
+	 * @return Result
+	 */
+	pub fn record(&mut self, origin: (i32, i32)) -> io::Result<()> {
+		let start = *self.start.get_or_insert_with(Instant::now);
+		let mut event = FrameEvent {
+			frame: self.frame,
+			time: start.elapsed().as_millis(),
+			..FrameEvent::default()
+		};
+		if let Some(state) = self.state {
+			let mouse = state.state.get_mouse();
+			event.pointer = (mouse.coords.0 - origin.0, mouse.coords.1 - origin.1);
+			event.buttons = mouse.button_pressed.into_iter().skip(1).collect();
+			event.keys = state
+				.state
+				.get_keys()
+				.iter()
+				.map(|key| format!("{:?}", key))
+				.collect();
+		}
+		if self.frame != 0 {
+			self.output.write_all(b",")?;
+		}
+		write!(self.output, "\n  {}", event)?;
+		self.frame += 1;
+		Ok(())
+	}
+}
+
+/* Close the JSON array and flush the output */
+impl Drop for EventLog {
+	fn drop(&mut self) {
+		if let Err(e) = self
+			.output
+			.write_all(b"\n]\n")
+			.and_then(|_| self.output.flush())
+		{
+			error!("Failed to write the events: {}", e);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_frame_event() {
+		let event = FrameEvent {
+			frame: 2,
+			time: 100,
+			pointer: (10, -5),
+			buttons: vec![true, false, false],
+			keys: vec![String::from("LControl"), String::from("C")],
+		};
+		assert_eq!(
+			"{\"frame\":2,\"time\":100,\"x\":10,\"y\":-5,\
+			\"buttons\":[true,false,false],\"keys\":[\"LControl\",\"C\"]}",
+			event.to_string()
+		);
+		assert_eq!(
+			"{\"frame\":0,\"time\":0,\"x\":0,\"y\":0,\"buttons\":[],\"keys\":[]}",
+			FrameEvent::default().to_string()
+		);
+		let event = FrameEvent {
+			keys: vec![String::from("\u{1b}\"\\")],
+			..FrameEvent::default()
+		};
+		let value: serde_json::Value =
+			serde_json::from_str(&event.to_string()).expect("Invalid JSON");
+		assert_eq!("\u{1b}\"\\", value["keys"][0]);
+	}
+}
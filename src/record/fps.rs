@@ -5,7 +5,9 @@ use std::time::{Duration, Instant};
 #[derive(Clone, Copy, Debug)]
 pub struct FpsClock {
 	pub fps: u32,
+	pub start: Option<Instant>,
 	last_tick_time: Instant,
+	frames: u32,
 }
 
 impl FpsClock {
@@ -18,16 +20,45 @@ impl FpsClock {
 	pub fn new(fps: u32) -> Self {
 		Self {
 			fps,
+			start: None,
 			last_tick_time: Instant::now(),
+			frames: 0,
 		}
 	}
 
+	/**
+	 * Set the start time for ticking at fixed intervals from it.
+	 *
+	 * @param  start
+	 * @return FpsClock
+	 */
+	pub fn with_start(mut self, start: Instant) -> Self {
+		self.start = Some(start);
+		self.frames = 0;
+		self
+	}
+
 	/**
 	 * Sleep the thread to run at the correct FPS.
 	 *
 	 * @return f32
 	 */
 	pub fn tick(&mut self) -> f32 {
+		if let Some(start) = self.start {
+			let next = start
+				+ Duration::from_secs_f64(self.frames as f64 / self.fps as f64);
+			self.frames += 1;
+			let now = Instant::now();
+			let diff = if next > now {
+				let wait = next - now;
+				thread::sleep(wait);
+				wait.as_nanos() as f32
+			} else {
+				-((now - next).as_nanos() as f32)
+			};
+			self.last_tick_time = Instant::now();
+			return diff;
+		}
 		let t = self.last_tick_time.elapsed();
 		let total_nanos = t.as_secs() * 1e9 as u64 + t.subsec_nanos() as u64;
 		let diff = ((1. / self.fps as f32) * 1e9) - (total_nanos as f32);
@@ -53,5 +84,11 @@ mod tests {
 			thread::sleep(Duration::from_nanos(i));
 			assert!(fps > fps_clock.tick());
 		}
+		let start = Instant::now();
+		let mut fps_clock = FpsClock::new(100).with_start(start);
+		for _ in 0..3 {
+			fps_clock.tick();
+		}
+		assert!(start.elapsed() >= Duration::from_millis(20));
 	}
 }
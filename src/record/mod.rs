@@ -1,16 +1,23 @@
+pub mod events;
 pub mod fps;
+pub mod overlay;
 pub mod settings;
 
 use crate::app::{AppError, AppResult};
 use crate::image::Image;
+use crate::record::events::EventLog;
 use crate::record::fps::FpsClock;
-use crate::record::settings::RecordSettings;
+use crate::record::overlay::InputOverlay;
+use crate::record::settings::{RecordSettings, StartTrigger, StopTrigger};
 use crate::util::state::InputState;
 use crate::window::Capture;
 use std::io::{self, Write};
+use std::mem;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc, Arc};
 use std::thread;
+use std::time::Instant;
 
 /* Asynchronous recording result */
 #[derive(Debug)]
@@ -52,6 +59,8 @@ pub struct Recorder<Window> {
 	channel: (mpsc::Sender<()>, mpsc::Receiver<()>),
 	gifski: bool,
 	settings: RecordSettings,
+	overlay: Option<InputOverlay>,
+	events: Option<EventLog>,
 }
 
 impl<Window> Recorder<Window>
@@ -65,6 +74,8 @@ where
 	 * @param  fps
 	 * @param  gifski
 	 * @param  settings
+	 * @param  overlay (Option)
+	 * @param  events (Option)
 	 * @return Recorder
 	 */
 	pub fn new(
@@ -72,6 +83,8 @@ where
 		fps: u32,
 		gifski: bool,
 		settings: RecordSettings,
+		overlay: Option<InputOverlay>,
+		events: Option<EventLog>,
 	) -> Self {
 		Self {
 			window,
@@ -79,9 +92,46 @@ where
 			channel: mpsc::channel(),
 			gifski,
 			settings,
+			overlay,
+			events,
 		}
 	}
 
+	/**
+	 * Set a shared start time for recording in sync with other recorders.
+	 *
+	 * @param  start
+	 * @return Recorder
+	 */
+	pub fn with_start(mut self, start: Instant) -> Self {
+		self.clock = self.clock.with_start(start);
+		self
+	}
+
+	/**
+	 * Get the image of the window with the overlays applied
+	 * and write the input events of the frame.
+	 *
+	 * @return Image (Option)
+	 */
+	fn get_image(&mut self) -> Option<Image> {
+		let mut image = self.window.get_image()?;
+		if self.overlay.is_none() && self.events.is_none() {
+			return Some(image);
+		}
+		let position = self.window.get_position();
+		if let Some(events) = self.events.as_mut() {
+			if let Err(e) = events.record(position) {
+				warn!("Failed to write the events: {}", e);
+				self.events = None;
+			}
+		}
+		if let Some(overlay) = self.overlay.as_mut() {
+			overlay.apply(&mut image, position);
+		}
+		Some(image)
+	}
+
 	/**
 	 * Get the maximum number of frames to record.
 	 *
@@ -105,6 +155,83 @@ where
 		}
 	}
 
+	/**
+	 * Get the number of unchanged frames for stopping the recording.
+	 *
+	 * @return usize
+	 */
+	fn get_idle_frames(&self) -> usize {
+		match self.settings.trigger.stop {
+			Some(StopTrigger::Idle(duration)) => {
+				((duration * self.clock.fps as f64).ceil() as usize).max(1)
+			}
+			_ => usize::MAX,
+		}
+	}
+
+	/**
+	 * Check if the image is unchanged from the previous frame
+	 * while waiting for the content to be idle.
+	 *
+	 * @param  image
+	 * @param  previous (Option)
+	 * @return bool
+	 */
+	fn is_idle(&self, image: &Image, previous: Option<&Image>) -> bool {
+		matches!(self.settings.trigger.stop, Some(StopTrigger::Idle(_)))
+			&& matches!(previous, Some(previous) if image.get_difference(previous) == 0.)
+	}
+
+	/**
+	 * Check if the file of the stop trigger exists.
+	 *
+	 * @return bool
+	 */
+	fn is_stop_file_present(&self) -> bool {
+		match self.settings.trigger.stop {
+			Some(StopTrigger::File(path)) if Path::new(path).exists() => {
+				debug!("\n");
+				info!("Stopped recording, {:?} exists.", path);
+				true
+			}
+			_ => false,
+		}
+	}
+
+	/**
+	 * Wait until the content of the window changes.
+	 *
+	 * @param  window
+	 * @param  clock
+	 * @param  is_recording
+	 * @param  input_state (Option)
+	 * @return bool (Result)
+	 */
+	fn wait_for_change<F: Fn() -> bool>(
+		window: &Window,
+		clock: &mut FpsClock,
+		is_recording: F,
+		input_state: Option<&InputState>,
+	) -> AppResult<bool> {
+		info!("Waiting for the content to change...");
+		let initial = window.get_image().ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get image"))
+		})?;
+		while is_recording() {
+			if matches!(input_state, Some(state) if state.check_cancel_keys()) {
+				warn!("User interrupt detected.");
+				return Ok(false);
+			}
+			clock.tick();
+			if let Some(image) = window.get_image() {
+				if image.get_difference(&initial) > 0. {
+					return Ok(true);
+				}
+			}
+		}
+		Ok(false)
+	}
+
 	/**
 	 * Record frames synchronously with blocking the current thread.
 	 *
@@ -116,55 +243,192 @@ where
 		input_state: Option<&InputState>,
 	) -> AppResult<Vec<Image>> {
 		let mut frames = Vec::new();
+		self.record_segments(input_state, usize::MAX, |images| {
+			frames = images;
+			Ok(())
+		})?;
+		Ok(frames)
+	}
+
+	/**
+	 * Record frames synchronously and pass them to the given
+	 * function as segments that contain the given number of frames.
+	 *
+	 * @param  input_state (Option)
+	 * @param  segment_frames
+	 * @param  on_segment
+	 * @return Result
+	 */
+	pub fn record_segments<F>(
+		&mut self,
+		input_state: Option<&InputState>,
+		segment_frames: usize,
+		mut on_segment: F,
+	) -> AppResult<()>
+	where
+		F: FnMut(Vec<Image>) -> AppResult<()>,
+	{
+		let mut frames = Vec::new();
+		let mut frame_count = 0;
+		let mut marker_pressed = false;
 		let recording = Arc::new(AtomicBool::new(true));
 		let rec_state = recording.clone();
-		ctrlc::set_handler(move || {
+		match ctrlc::set_handler(move || {
 			rec_state.store(false, Ordering::SeqCst);
-		})?;
+		}) {
+			Err(ctrlc::Error::MultipleHandlers) => {
+				debug!("Ctrl-C handler is already set.")
+			}
+			result => result?,
+		}
 		self.window.show_countdown();
+		if self.settings.trigger.start == Some(StartTrigger::Change)
+			&& !Self::wait_for_change(
+				&self.window,
+				&mut self.clock,
+				|| recording.load(Ordering::SeqCst),
+				input_state,
+			)? {
+			return Ok(());
+		}
 		let max_frames = self.get_max_frames();
-		while recording.load(Ordering::SeqCst) && frames.len() < max_frames {
+		let idle_frames = self.get_idle_frames();
+		let mut idle_count = 0;
+		let mut previous = None;
+		while recording.load(Ordering::SeqCst) && frame_count < max_frames {
 			if let Some(state) = input_state {
 				if state.check_cancel_keys() {
-					frames.clear();
 					debug!("\n");
 					warn!("User interrupt detected.");
-					break;
+					return Ok(());
 				} else if state.check_action() {
 					break;
 				}
 			}
+			let marker =
+				matches!(input_state, Some(state) if state.check_marker_keys());
+			if self.is_stop_file_present() {
+				break;
+			}
 			self.clock.tick();
-			frames.push(self.window.get_image().ok_or_else(|| {
-				AppError::FrameError(String::from("Failed to get image"))
-			})?);
-			debug!("Frames: {}\r", frames.len());
+			let mut image = match self.get_image() {
+				Some(image) => image,
+				None if self.settings.trigger.stop == Some(StopTrigger::Close) => {
+					debug!("\n");
+					info!("Stopped recording, the window is closed.");
+					break;
+				}
+				None => {
+					return Err(AppError::FrameError(String::from(
+						"Failed to get image",
+					)))
+				}
+			};
+			idle_count = if self.is_idle(&image, previous.as_ref()) {
+				idle_count + 1
+			} else {
+				0
+			};
+			if idle_frames != usize::MAX {
+				previous = Some(image.clone());
+			}
+			if marker && !marker_pressed {
+				info!("Added a marker at frame {}.", frame_count);
+				image.marker = true;
+			}
+			marker_pressed = marker;
+			frames.push(image);
+			frame_count += 1;
+			debug!("Frames: {}\r", frame_count);
 			io::stdout().flush()?;
+			if idle_count >= idle_frames {
+				debug!("\n");
+				info!("Stopped recording, the content is idle.");
+				break;
+			}
+			if frames.len() >= segment_frames {
+				debug!("\n");
+				on_segment(mem::take(&mut frames))?;
+			}
 		}
 		debug!("\n");
-		Ok(frames)
+		if !frames.is_empty() {
+			on_segment(frames)?;
+		}
+		Ok(())
 	}
 
 	/**
 	 * Record frames asynchronously and without blocking.
 	 *
+	 * @param  input_state (Option)
 	 * @return RecordResult
 	 */
-	pub fn record_async(mut self) -> RecordResult<Vec<Image>> {
+	pub fn record_async(
+		mut self,
+		input_state: Option<&'static InputState>,
+	) -> RecordResult<Vec<Image>> {
 		let mut frames = Vec::new();
 		RecordResult::new(
 			self.channel.0.clone(),
 			thread::spawn(move || {
-				self.window.show_countdown();
+				if self.clock.start.is_none() {
+					self.window.show_countdown();
+				}
+				let receiver = &self.channel.1;
+				if self.settings.trigger.start == Some(StartTrigger::Change)
+					&& !Self::wait_for_change(
+						&self.window,
+						&mut self.clock,
+						|| receiver.try_recv().is_err(),
+						input_state,
+					)
+					.expect("Failed to wait for the change")
+				{
+					return frames;
+				}
 				let max_frames = self.get_max_frames();
+				let idle_frames = self.get_idle_frames();
+				let mut idle_count = 0;
+				let mut stopped = false;
+				let mut marker_pressed = false;
 				while self.channel.1.try_recv().is_err() {
 					self.clock.tick();
-					if frames.len() < max_frames {
-						frames.push(
-							self.window
-								.get_image()
-								.expect("Failed to get the image"),
-						);
+					if !stopped && frames.len() < max_frames {
+						if self.is_stop_file_present() {
+							stopped = true;
+							continue;
+						}
+						let marker = matches!(input_state,
+							Some(state) if state.check_marker_keys());
+						let mut image = match self.get_image() {
+							Some(image) => image,
+							None if self.settings.trigger.stop
+								== Some(StopTrigger::Close) =>
+							{
+								debug!("\n");
+								info!("Stopped recording, the window is closed.");
+								stopped = true;
+								continue;
+							}
+							None => panic!("Failed to get the image"),
+						};
+						idle_count = if self.is_idle(&image, frames.last()) {
+							idle_count + 1
+						} else {
+							0
+						};
+						if marker && !marker_pressed {
+							info!("Added a marker at frame {}.", frames.len());
+							image.marker = true;
+						}
+						marker_pressed = marker;
+						frames.push(image);
+						if idle_count >= idle_frames {
+							debug!("\n");
+							info!("Stopped recording, the content is idle.");
+							stopped = true;
+						}
 						debug!("Frames: {}\r", frames.len());
 						io::stdout().flush().expect("Failed to flush stdout");
 					}
@@ -181,19 +445,58 @@ mod tests {
 	use super::*;
 	use crate::record::settings::RecordSettings;
 	use crate::window::test::TestWindow;
-	use pretty_assertions::assert_ne;
+	use pretty_assertions::{assert_eq, assert_ne};
 	use std::thread;
 	use std::time::Duration;
 	#[test]
 	fn test_record() {
 		let window = TestWindow::default();
-		let recorder = Recorder::new(window, 10, false, RecordSettings::default());
-		let record = recorder.record_async();
+		let recorder =
+			Recorder::new(window, 10, false, RecordSettings::default(), None, None);
+		let record = recorder.record_async(None);
 		thread::sleep(Duration::from_millis(200));
 		assert!(record.get().unwrap().unwrap().len() > 0);
 		let mut recorder =
-			Recorder::new(window, 10, false, RecordSettings::default());
+			Recorder::new(window, 10, false, RecordSettings::default(), None, None);
+		recorder.settings.trigger.stop = Some(StopTrigger::Idle(0.1));
+		let record = recorder.record_async(None);
+		thread::sleep(Duration::from_millis(400));
+		assert_eq!(2, record.get().unwrap().unwrap().len());
+		let mut recorder =
+			Recorder::new(window, 10, false, RecordSettings::default(), None, None);
 		recorder.settings.time.duration = Some(0.2);
 		assert_ne!(0, recorder.record_sync(None).unwrap().len());
 	}
+	#[test]
+	fn test_record_segments() {
+		let window = TestWindow::default();
+		let mut recorder =
+			Recorder::new(window, 10, false, RecordSettings::default(), None, None);
+		recorder.settings.time.duration = Some(0.5);
+		let mut segments = Vec::new();
+		recorder
+			.record_segments(None, 2, |images| {
+				segments.push(images.len());
+				Ok(())
+			})
+			.unwrap();
+		assert_ne!(0, segments.len());
+		assert!(segments[..segments.len() - 1].iter().all(|v| *v == 2));
+		let mut recorder =
+			Recorder::new(window, 10, false, RecordSettings::default(), None, None);
+		recorder.settings.trigger.stop = Some(StopTrigger::Idle(0.1));
+		let mut frame_count = 0;
+		recorder
+			.record_segments(None, 1, |images| {
+				frame_count += images.len();
+				Ok(())
+			})
+			.unwrap();
+		assert_eq!(2, frame_count);
+		let mut recorder =
+			Recorder::new(window, 10, false, RecordSettings::default(), None, None);
+		assert_eq!(usize::MAX, recorder.get_idle_frames());
+		recorder.settings.trigger.stop = Some(StopTrigger::Idle(0.25));
+		assert_eq!(3, recorder.get_idle_frames());
+	}
 }
@@ -0,0 +1,334 @@
+use crate::image::font::BitmapFont;
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use crate::record::settings::{OverlayCorner, OverlaySettings};
+use crate::util::state::InputState;
+use device_query::{DeviceQuery, Keycode, MouseState};
+use image::Bgra;
+
+/* Modifier keys in the order of display */
+const MODIFIER_KEYS: [Keycode; 7] = [
+	Keycode::LControl,
+	Keycode::RControl,
+	Keycode::LShift,
+	Keycode::RShift,
+	Keycode::LAlt,
+	Keycode::RAlt,
+	Keycode::Meta,
+];
+
+/* Maximum number of key captions to show at once */
+const MAX_CAPTIONS: usize = 3;
+
+/* Caption of a pressed key combination */
+#[derive(Clone, Debug, PartialEq)]
+struct KeyCaption {
+	text: String,
+	age: u32,
+}
+
+/* Mouse click at a position on the screen */
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MouseClick {
+	position: (i32, i32),
+	button: usize,
+	age: u32,
+}
+
+/* Overlay for visualizing the user input on frames */
+pub struct InputOverlay {
+	settings: OverlaySettings,
+	state: Option<&'static InputState>,
+	fps: u32,
+	pressed: Vec<Keycode>,
+	captions: Vec<KeyCaption>,
+	buttons: Vec<bool>,
+	clicks: Vec<MouseClick>,
+}
+
+impl InputOverlay {
+	/**
+	 * Create a new InputOverlay object.
+	 *
+	 * @param  settings
+	 * @param  state (Option)
+	 * @param  fps
+	 * @return InputOverlay
+	 */
+	pub fn new(
+		settings: OverlaySettings,
+		state: Option<&'static InputState>,
+		fps: u32,
+	) -> Self {
+		Self {
+			settings,
+			state,
+			fps: fps.max(1),
+			pressed: Vec::new(),
+			captions: Vec::new(),
+			buttons: Vec::new(),
+			clicks: Vec::new(),
+		}
+	}
+
+	/**
+	 * Get the number of frames that a caption stays visible.
+	 *
+	 * @return u32
+	 */
+	fn get_caption_frames(&self) -> u32 {
+		self.fps * 2
+	}
+
+	/**
+	 * Update the captions with the currently pressed keys.
+	 *
+	 * @param  keys
+	 */
+	fn update_keys(&mut self, keys: Vec<Keycode>) {
+		let caption_frames = self.get_caption_frames();
+		self.captions
+			.iter_mut()
+			.for_each(|caption| caption.age += 1);
+		self.captions.retain(|caption| caption.age < caption_frames);
+		let new_keys = keys
+			.iter()
+			.filter(|key| {
+				!MODIFIER_KEYS.contains(key) && !self.pressed.contains(key)
+			})
+			.map(|key| format!("{:?}", key))
+			.collect::<Vec<String>>();
+		if !new_keys.is_empty() {
+			let mut combo = MODIFIER_KEYS
+				.iter()
+				.filter(|key| keys.contains(key))
+				.map(|key| format!("{:?}", key))
+				.collect::<Vec<String>>();
+			combo.extend(new_keys);
+			self.captions.push(KeyCaption {
+				text: combo.join("-"),
+				age: 0,
+			});
+			if self.captions.len() > MAX_CAPTIONS {
+				self.captions.remove(0);
+			}
+		}
+		self.pressed = keys;
+	}
+
+	/**
+	 * Draw the key captions on the image.
+	 *
+	 * @param  image
+	 * @param  corner
+	 */
+	fn draw_keys(&self, image: &mut Image, corner: OverlayCorner) {
+		let font = BitmapFont::new(image.geometry.height / 240);
+		let (_, line_height) = font.get_text_size("");
+		let padding = line_height / 3;
+		let margin = line_height / 2;
+		let caption_frames = self.get_caption_frames();
+		let fade_frames = (caption_frames / 4).max(1);
+		let mut offset = margin;
+		for caption in self.captions.iter().rev() {
+			let opacity = if caption.age + fade_frames > caption_frames {
+				(caption_frames - caption.age) as f32 / fade_frames as f32
+			} else {
+				1.
+			};
+			let (text_width, text_height) = font.get_text_size(&caption.text);
+			let (width, height) =
+				(text_width + padding * 2, text_height + padding * 2);
+			let x = match corner {
+				OverlayCorner::TopLeft | OverlayCorner::BottomLeft => margin as i32,
+				_ => image.geometry.width as i32 - (width + margin) as i32,
+			};
+			let y = match corner {
+				OverlayCorner::TopLeft | OverlayCorner::TopRight => offset as i32,
+				_ => image.geometry.height as i32 - (height + offset) as i32,
+			};
+			image.fill_rect(
+				Geometry::new(x, y, width, height),
+				Bgra::from([0, 0, 0, 255]),
+				opacity * 0.6,
+			);
+			font.draw(
+				image,
+				&caption.text,
+				(x + padding as i32, y + padding as i32),
+				Bgra::from([255, 255, 255, 255]),
+				opacity,
+			);
+			offset += height + padding;
+		}
+	}
+
+	/**
+	 * Get the number of frames that a click effect stays visible.
+	 *
+	 * @return u32
+	 */
+	fn get_click_frames(&self) -> u32 {
+		(self.fps / 2).max(3)
+	}
+
+	/**
+	 * Update the clicks with the current mouse state.
+	 *
+	 * @param  mouse
+	 */
+	fn update_mouse(&mut self, mouse: MouseState) {
+		let click_frames = self.get_click_frames();
+		self.clicks.iter_mut().for_each(|click| click.age += 1);
+		self.clicks.retain(|click| click.age < click_frames);
+		for (button, pressed) in mouse.button_pressed.iter().enumerate().skip(1) {
+			if *pressed && !self.buttons.get(button).copied().unwrap_or_default() {
+				self.clicks.push(MouseClick {
+					position: mouse.coords,
+					button,
+					age: 0,
+				});
+			}
+		}
+		self.buttons = mouse.button_pressed;
+	}
+
+	/**
+	 * Draw the click effects on the image.
+	 *
+	 * @param  image
+	 * @param  origin
+	 */
+	fn draw_clicks(&self, image: &mut Image, origin: (i32, i32)) {
+		let scale = (image.geometry.height / 240).max(1) as f32;
+		let click_frames = self.get_click_frames();
+		for click in &self.clicks {
+			let progress = click.age as f32 / click_frames as f32;
+			let radius = scale * (4. + 10. * progress);
+			let opacity = 0.8 * (1. - progress);
+			let color = match click.button {
+				1 => Bgra::from([0, 200, 255, 255]),
+				3 => Bgra::from([255, 120, 0, 255]),
+				_ => Bgra::from([80, 220, 80, 255]),
+			};
+			let center = (
+				(click.position.0 - origin.0) as f32,
+				(click.position.1 - origin.1) as f32,
+			);
+			let outer = radius.ceil() as i32 + 1;
+			for dy in -outer..=outer {
+				for dx in -outer..=outer {
+					let distance = ((dx * dx + dy * dy) as f32).sqrt();
+					if (distance - radius).abs() <= scale {
+						image.blend_pixel(
+							center.0 as i32 + dx,
+							center.1 as i32 + dy,
+							color,
+							opacity,
+						);
+					}
+				}
+			}
+		}
+	}
+
+	/**
+	 * Update the input state and draw the overlays on the image.
+	 *
+	 * @param  image
+	 * @param  origin
+	 */
+	pub fn apply(&mut self, image: &mut Image, origin: (i32, i32)) {
+		if self.settings.clicks {
+			if let Some(state) = self.state {
+				self.update_mouse(state.state.get_mouse());
+			}
+			self.draw_clicks(image, origin);
+		}
+		if let Some(corner) = self.settings.keys {
+			if let Some(state) = self.state {
+				self.update_keys(state.state.get_keys());
+			}
+			self.draw_keys(image, corner);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_input_overlay() {
+		let mut overlay = InputOverlay::new(
+			OverlaySettings::new(Some(OverlayCorner::TopRight), true),
+			None,
+			2,
+		);
+		overlay.update_keys(vec![Keycode::LControl]);
+		assert!(overlay.captions.is_empty());
+		overlay.update_keys(vec![Keycode::LControl, Keycode::LShift, Keycode::P]);
+		overlay.update_keys(vec![Keycode::LControl, Keycode::LShift, Keycode::P]);
+		assert_eq!(1, overlay.captions.len());
+		assert_eq!("LControl-LShift-P", overlay.captions[0].text);
+		overlay.update_keys(vec![Keycode::A]);
+		assert_eq!(
+			vec!["LControl-LShift-P", "A"],
+			overlay
+				.captions
+				.iter()
+				.map(|caption| caption.text.as_str())
+				.collect::<Vec<&str>>()
+		);
+		let geometry = Geometry::new(0, 0, 100, 50);
+		let mut image =
+			Image::new(vec![Bgra::from([0, 0, 0, 255]); 5000], false, geometry);
+		overlay.draw_keys(&mut image, OverlayCorner::TopRight);
+		assert!(image.get_data(image::ExtendedColorType::L8)[..100]
+			.iter()
+			.all(|v| *v == 0));
+		assert!(image
+			.get_data(image::ExtendedColorType::L8)
+			.iter()
+			.any(|v| *v > 200));
+		for _ in 0..2 {
+			overlay.update_keys(Vec::new());
+		}
+		assert_eq!(1, overlay.captions.len());
+		for _ in 0..2 {
+			overlay.update_keys(Vec::new());
+		}
+		assert!(overlay.captions.is_empty());
+	}
+	#[test]
+	fn test_click_overlay() {
+		let mut overlay =
+			InputOverlay::new(OverlaySettings::new(None, true), None, 6);
+		let mouse = |coords, left, right| MouseState {
+			coords,
+			button_pressed: vec![false, left, false, right],
+		};
+		overlay.update_mouse(mouse((20, 20), true, false));
+		overlay.update_mouse(mouse((25, 20), true, false));
+		overlay.update_mouse(mouse((30, 30), false, true));
+		assert_eq!(
+			vec![((20, 20), 1, 2), ((30, 30), 3, 0)],
+			overlay
+				.clicks
+				.iter()
+				.map(|click| (click.position, click.button, click.age))
+				.collect::<Vec<((i32, i32), usize, u32)>>()
+		);
+		let geometry = Geometry::new(0, 0, 40, 40);
+		let mut image =
+			Image::new(vec![Bgra::from([0, 0, 0, 255]); 1600], false, geometry);
+		overlay.draw_clicks(&mut image, (10, 10));
+		let data = image.get_data(image::ExtendedColorType::Rgb8);
+		assert_eq!(vec![0, 0, 0], data[(10 * 40 + 10) * 3..][..3].to_vec());
+		assert!(data.iter().any(|v| *v > 0));
+		for _ in 0..3 {
+			overlay.update_mouse(mouse((30, 30), false, false));
+		}
+		assert!(overlay.clicks.is_empty());
+	}
+}
@@ -1,3 +1,4 @@
+use crate::anim::AnimFormat;
 use crate::args::matches::ArgMatches;
 use crate::args::parser::ArgParser;
 use crate::image::geometry::Geometry;
@@ -11,6 +12,7 @@ pub struct RecordTime {
 	pub countdown: u64,
 	pub timeout: u64,
 	pub interval: u64,
+	pub segment: Option<f64>,
 }
 
 /* Default initialization values for RecordTime */
@@ -21,6 +23,7 @@ impl Default for RecordTime {
 			countdown: 3,
 			timeout: 300,
 			interval: 10,
+			segment: None,
 		}
 	}
 }
@@ -33,6 +36,7 @@ impl RecordTime {
 	 * @param  countdown
 	 * @param  timeout
 	 * @param  interval
+	 * @param  segment (Option)
 	 * @return RecordTime
 	 */
 	pub fn new(
@@ -40,12 +44,14 @@ impl RecordTime {
 		countdown: u64,
 		timeout: u64,
 		interval: u64,
+		segment: Option<f64>,
 	) -> Self {
 		Self {
 			duration,
 			countdown,
 			timeout,
 			interval,
+			segment,
 		}
 	}
 
@@ -64,8 +70,406 @@ impl RecordTime {
 			parser.parse("countdown", Self::default().countdown),
 			parser.parse("timeout", Self::default().timeout),
 			parser.parse("interval", Self::default().interval),
+			parser
+				.args
+				.as_ref()
+				.and_then(|matches| matches.value_of("segment"))
+				.and_then(Self::parse_duration),
 		)
 	}
+
+	/**
+	 * Parse a duration in seconds from a string with an optional unit.
+	 *
+	 * @param  duration (e.g. 500ms, 10s, 5m, 1h)
+	 * @return f64 (Option)
+	 */
+	pub fn parse_duration(duration: &str) -> Option<f64> {
+		let duration = duration.trim();
+		let (value, multiplier) = if let Some(v) = duration.strip_suffix("ms") {
+			(v, 1e-3)
+		} else if let Some(v) = duration.strip_suffix('s') {
+			(v, 1.)
+		} else if let Some(v) = duration.strip_suffix('m') {
+			(v, 60.)
+		} else if let Some(v) = duration.strip_suffix('h') {
+			(v, 3600.)
+		} else {
+			(duration, 1.)
+		};
+		match value.trim().parse::<f64>() {
+			Ok(value) if value > 0. => Some(value * multiplier),
+			_ => None,
+		}
+	}
+}
+
+/* Time-lapse capture settings */
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimelapseSettings {
+	pub every: Option<f64>,
+	pub count: Option<usize>,
+	pub format: Option<AnimFormat>,
+}
+
+impl TimelapseSettings {
+	/**
+	 * Create a new TimelapseSettings object.
+	 *
+	 * @param  every (Option)
+	 * @param  count (Option)
+	 * @param  format (Option)
+	 * @return TimelapseSettings
+	 */
+	pub fn new(
+		every: Option<f64>,
+		count: Option<usize>,
+		format: Option<AnimFormat>,
+	) -> Self {
+		Self {
+			every,
+			count,
+			format,
+		}
+	}
+
+	/**
+	 * Create a new TimelapseSettings object from arguments.
+	 *
+	 * @param  matches
+	 * @return TimelapseSettings
+	 */
+	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(matches, "capture"))
+	}
+
+	/**
+	 * Create a TimelapseSettings object from an argument parser.
+	 *
+	 * @param  parser
+	 * @return TimelapseSettings
+	 */
+	fn from_parser(parser: ArgParser<'_>) -> Self {
+		match parser.args {
+			Some(ref matches) => Self::new(
+				matches
+					.value_of("every")
+					.and_then(RecordTime::parse_duration),
+				match parser.parse("count", 0) {
+					count if count > 0 => Some(count),
+					_ => None,
+				},
+				match matches.value_of("assemble") {
+					Some("gif") => Some(AnimFormat::Gif),
+					Some("apng") => Some(AnimFormat::Apng),
+					Some("webp") => Some(AnimFormat::WebP),
+					Some("y4m") => Some(AnimFormat::Y4m),
+					Some("avi") => Some(AnimFormat::Avi),
+					Some("tiff") => Some(AnimFormat::Tiff),
+					Some("pdf") => Some(AnimFormat::Pdf),
+					_ => None,
+				},
+			),
+			None => Self::default(),
+		}
+	}
+
+	/**
+	 * Check if the time-lapse mode is enabled.
+	 *
+	 * @return bool
+	 */
+	pub fn is_enabled(&self) -> bool {
+		self.every.is_some()
+	}
+}
+
+/* Step recording settings */
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StepSettings {
+	pub hold: Option<f64>,
+	pub dir: Option<&'static str>,
+}
+
+impl StepSettings {
+	/**
+	 * Create a new StepSettings object.
+	 *
+	 * @param  hold (Option)
+	 * @param  dir (Option)
+	 * @return StepSettings
+	 */
+	pub fn new(hold: Option<f64>, dir: Option<&'static str>) -> Self {
+		Self { hold, dir }
+	}
+
+	/**
+	 * Create a new StepSettings object from arguments.
+	 *
+	 * @param  matches
+	 * @return StepSettings
+	 */
+	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(matches, "record"))
+	}
+
+	/**
+	 * Create a StepSettings object from an argument parser.
+	 *
+	 * @param  parser
+	 * @return StepSettings
+	 */
+	fn from_parser(parser: ArgParser<'_>) -> Self {
+		match parser.args {
+			Some(ref matches) => Self::new(
+				match matches.value_of("steps") {
+					Some("false") => None,
+					Some(hold) => RecordTime::parse_duration(hold),
+					None if matches.is_present("steps") => Some(2.),
+					None => None,
+				},
+				match matches.value_of("step-dir") {
+					Some(dir) => Some(Box::leak(dir.to_string().into_boxed_str())),
+					None => None,
+				},
+			),
+			None => Self::default(),
+		}
+	}
+
+	/**
+	 * Check if the step recording is enabled.
+	 *
+	 * @return bool
+	 */
+	pub fn is_enabled(&self) -> bool {
+		self.hold.is_some()
+	}
+}
+
+/* Trigger for starting the recording */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StartTrigger {
+	Window(&'static str),
+	Change,
+}
+
+impl StartTrigger {
+	/**
+	 * Parse StartTrigger from a string.
+	 *
+	 * @param  trigger (e.g. window:NAME, change)
+	 * @return StartTrigger (Option)
+	 */
+	pub fn parse(trigger: &str) -> Option<Self> {
+		let mut values = trigger.trim().splitn(2, ':');
+		match (values.next(), values.next()) {
+			(Some("window"), Some(name)) if !name.is_empty() => {
+				Some(Self::Window(Box::leak(name.to_string().into_boxed_str())))
+			}
+			(Some("change"), None) => Some(Self::Change),
+			_ => None,
+		}
+	}
+}
+
+/* Trigger for stopping the recording */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StopTrigger {
+	Close,
+	Idle(f64),
+	File(&'static str),
+}
+
+impl StopTrigger {
+	/**
+	 * Parse StopTrigger from a string.
+	 *
+	 * @param  trigger (e.g. close, idle:T, file:PATH)
+	 * @return StopTrigger (Option)
+	 */
+	pub fn parse(trigger: &str) -> Option<Self> {
+		let mut values = trigger.trim().splitn(2, ':');
+		match (values.next(), values.next()) {
+			(Some("close"), None) => Some(Self::Close),
+			(Some("idle"), Some(duration)) => {
+				RecordTime::parse_duration(duration).map(Self::Idle)
+			}
+			(Some("file"), Some(path)) if !path.is_empty() => {
+				Some(Self::File(Box::leak(path.to_string().into_boxed_str())))
+			}
+			_ => None,
+		}
+	}
+}
+
+/* Start and stop triggers of recording */
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TriggerSettings {
+	pub start: Option<StartTrigger>,
+	pub stop: Option<StopTrigger>,
+}
+
+impl TriggerSettings {
+	/**
+	 * Create a new TriggerSettings object.
+	 *
+	 * @param  start (Option)
+	 * @param  stop (Option)
+	 * @return TriggerSettings
+	 */
+	pub fn new(start: Option<StartTrigger>, stop: Option<StopTrigger>) -> Self {
+		Self { start, stop }
+	}
+
+	/**
+	 * Create a TriggerSettings object from parsed arguments.
+	 *
+	 * @param  matches
+	 * @return TriggerSettings
+	 */
+	fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::new(
+			matches.value_of("start-on").and_then(StartTrigger::parse),
+			matches.value_of("stop-on").and_then(StopTrigger::parse),
+		)
+	}
+}
+
+/* Settings for recording multiple windows */
+#[derive(Clone, Debug, Default)]
+pub struct MultiRecordSettings {
+	pub windows: Vec<String>,
+	pub compose: bool,
+}
+
+impl MultiRecordSettings {
+	/**
+	 * Create a new MultiRecordSettings object.
+	 *
+	 * @param  windows
+	 * @param  compose
+	 * @return MultiRecordSettings
+	 */
+	pub fn new(windows: Vec<String>, compose: bool) -> Self {
+		Self { windows, compose }
+	}
+
+	/**
+	 * Create a new MultiRecordSettings object from arguments.
+	 *
+	 * @param  matches
+	 * @return MultiRecordSettings
+	 */
+	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(matches, "record"))
+	}
+
+	/**
+	 * Create a MultiRecordSettings object from an argument parser.
+	 *
+	 * @param  parser
+	 * @return MultiRecordSettings
+	 */
+	fn from_parser(parser: ArgParser<'_>) -> Self {
+		match parser.args {
+			Some(ref matches) => Self::new(
+				matches
+					.value_of("windows")
+					.unwrap_or_default()
+					.split(',')
+					.map(|window| window.trim().to_string())
+					.filter(|window| !window.is_empty())
+					.collect(),
+				matches.is_present("compose"),
+			),
+			None => Self::default(),
+		}
+	}
+
+	/**
+	 * Check if recording multiple windows is enabled.
+	 *
+	 * @return bool
+	 */
+	pub fn is_enabled(&self) -> bool {
+		!self.windows.is_empty()
+	}
+}
+
+/* Corner of the frame for drawing overlays */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverlayCorner {
+	TopLeft,
+	TopRight,
+	BottomLeft,
+	BottomRight,
+}
+
+impl OverlayCorner {
+	/**
+	 * Parse OverlayCorner from a string.
+	 *
+	 * @param  corner
+	 * @return OverlayCorner
+	 */
+	pub fn parse(corner: &str) -> Self {
+		match corner {
+			"top-left" => Self::TopLeft,
+			"top-right" => Self::TopRight,
+			"bottom-right" => Self::BottomRight,
+			_ => Self::BottomLeft,
+		}
+	}
+}
+
+/* Input overlay settings of recording */
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OverlaySettings {
+	pub keys: Option<OverlayCorner>,
+	pub clicks: bool,
+}
+
+impl OverlaySettings {
+	/**
+	 * Create a new OverlaySettings object.
+	 *
+	 * @param  keys (Option)
+	 * @param  clicks
+	 * @return OverlaySettings
+	 */
+	pub fn new(keys: Option<OverlayCorner>, clicks: bool) -> Self {
+		Self { keys, clicks }
+	}
+
+	/**
+	 * Create an OverlaySettings object from parsed arguments.
+	 *
+	 * @param  matches
+	 * @return OverlaySettings
+	 */
+	fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::new(
+			match matches.value_of("show-keys") {
+				Some("false") => None,
+				Some(corner) => Some(OverlayCorner::parse(corner)),
+				None if matches.is_present("show-keys") => {
+					Some(OverlayCorner::BottomLeft)
+				}
+				None => None,
+			},
+			matches.is_present("show-clicks"),
+		)
+	}
+
+	/**
+	 * Check if any of the overlays is enabled.
+	 *
+	 * @return bool
+	 */
+	pub fn is_enabled(&self) -> bool {
+		self.keys.is_some() || self.clicks
+	}
 }
 
 /* Flag values of recording */
@@ -74,6 +478,7 @@ pub struct RecordFlag {
 	pub alpha: bool,
 	pub action_keys: Option<&'static str>,
 	pub cancel_keys: Option<&'static str>,
+	pub marker_keys: Option<&'static str>,
 	pub font: Option<&'static str>,
 	pub monitor: Option<usize>,
 	pub select: bool,
@@ -87,6 +492,7 @@ impl Default for RecordFlag {
 			alpha: false,
 			action_keys: Some(""),
 			cancel_keys: Some(""),
+			marker_keys: None,
 			font: None,
 			monitor: None,
 			select: true,
@@ -102,16 +508,19 @@ impl RecordFlag {
 	 * @param  alpha
 	 * @param  action_keys (Option)
 	 * @param  cancel_keys (Option)
+	 * @param  marker_keys (Option)
 	 * @param  font
 	 * @param  monitor (Option)
 	 * @param  select
 	 * @param  mouse
 	 * @return RecordFlag
 	 */
+	#[allow(clippy::too_many_arguments)]
 	pub fn new(
 		alpha: bool,
 		action_keys: Option<&'static str>,
 		cancel_keys: Option<&'static str>,
+		marker_keys: Option<&'static str>,
 		font: &str,
 		monitor: Option<usize>,
 		select: bool,
@@ -121,6 +530,7 @@ impl RecordFlag {
 			alpha,
 			action_keys,
 			cancel_keys,
+			marker_keys,
 			font: if font.is_empty() {
 				None
 			} else {
@@ -160,7 +570,9 @@ impl RecordWindow {
 			} else {
 				None
 			};
-		if matches.is_present("focus") && !matches.is_present("monitor") {
+		if matches.is_present("virtual") {
+			Self::Root(None)
+		} else if matches.is_present("focus") && !matches.is_present("monitor") {
 			Self::Focus(size, matches.is_present("parent"))
 		} else if matches.is_present("root") || matches.is_present("monitor") {
 			Self::Root(size)
@@ -180,6 +592,10 @@ pub struct RecordSettings {
 	pub time: RecordTime,
 	pub flag: RecordFlag,
 	pub window: RecordWindow,
+	pub overlay: OverlaySettings,
+	pub events: Option<&'static str>,
+	pub trigger: TriggerSettings,
+	pub virtual_size: Option<Geometry>,
 }
 
 /* Default initialization values for RecordSettings */
@@ -193,6 +609,10 @@ impl Default for RecordSettings {
 			time: RecordTime::default(),
 			flag: RecordFlag::default(),
 			window: RecordWindow::Focus(Some(Geometry::default()), false),
+			overlay: OverlaySettings::default(),
+			events: None,
+			trigger: TriggerSettings::default(),
+			virtual_size: None,
 		}
 	}
 }
@@ -208,8 +628,13 @@ impl RecordSettings {
 	 * @param  time
 	 * @param  flag
 	 * @param  window
+	 * @param  overlay
+	 * @param  events (Option)
+	 * @param  trigger
+	 * @param  virtual_size (Option)
 	 * @return RecordSettings
 	 */
+	#[allow(clippy::too_many_arguments)]
 	pub fn new(
 		command: Option<&'static str>,
 		color: u64,
@@ -218,6 +643,10 @@ impl RecordSettings {
 		time: RecordTime,
 		flag: RecordFlag,
 		window: RecordWindow,
+		overlay: OverlaySettings,
+		events: Option<&'static str>,
+		trigger: TriggerSettings,
+		virtual_size: Option<Geometry>,
 	) -> Self {
 		Self {
 			command,
@@ -227,6 +656,10 @@ impl RecordSettings {
 			time,
 			flag,
 			window,
+			overlay,
+			events,
+			trigger,
+			virtual_size,
 		}
 	}
 
@@ -291,6 +724,12 @@ impl RecordSettings {
 							.to_string()
 							.into_boxed_str(),
 					)),
+					match matches.value_of("marker-keys") {
+						Some(keys) => {
+							Some(Box::leak(keys.to_string().into_boxed_str()))
+						}
+						None => None,
+					},
 					matches.value_of("font").unwrap_or_default(),
 					matches.value_of("monitor").and_then(|v| v.parse().ok()),
 					if matches.value_of("size").unwrap_or_default().contains('+') {
@@ -301,6 +740,13 @@ impl RecordSettings {
 					matches.is_present("mouse"),
 				),
 				RecordWindow::from_args(matches),
+				OverlaySettings::from_args(matches),
+				match matches.value_of("events") {
+					Some(path) => Some(Box::leak(path.to_string().into_boxed_str())),
+					_ => None,
+				},
+				TriggerSettings::from_args(matches),
+				matches.value_of("virtual").map(Geometry::parse),
 			),
 			None => RecordSettings::default(),
 		}
@@ -370,6 +816,11 @@ mod tests {
 					.long("cancel-keys")
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("marker-keys")
+					.long("marker-keys")
+					.takes_value(true),
+			)
 			.arg(Arg::with_name("border").long("border").takes_value(true))
 			.arg(Arg::with_name("padding").long("padding").takes_value(true))
 			.arg(Arg::with_name("size").long("size").takes_value(true))
@@ -389,6 +840,14 @@ mod tests {
 					.long("interval")
 					.takes_value(true),
 			)
+			.arg(Arg::with_name("segment").long("segment").takes_value(true))
+			.arg(
+				Arg::with_name("show-keys")
+					.long("show-keys")
+					.takes_value(true),
+			)
+			.arg(Arg::with_name("show-clicks").long("show-clicks"))
+			.arg(Arg::with_name("events").long("events").takes_value(true))
 			.arg(Arg::with_name("root").long("root"))
 			.arg(Arg::with_name("focus").long("focus"))
 			.arg(Arg::with_name("with-alpha").long("with-alpha"))
@@ -399,6 +858,8 @@ mod tests {
 				"LControl-Q,S",
 				"--cancel-keys",
 				"X",
+				"--marker-keys",
+				"LAlt-M",
 				"--border",
 				"10",
 				"--padding",
@@ -413,6 +874,13 @@ mod tests {
 				"300",
 				"--interval",
 				"12",
+				"--segment",
+				"1m",
+				"--show-keys",
+				"top-right",
+				"--show-clicks",
+				"--events",
+				"events.json",
 				"--root",
 				"--with-alpha",
 			]);
@@ -424,6 +892,8 @@ mod tests {
 		assert_eq!(2, record_settings.time.countdown);
 		assert_eq!(300, record_settings.time.timeout);
 		assert_eq!(12, record_settings.time.interval);
+		assert_eq!(Some(60.), record_settings.time.segment);
+		assert_eq!(Some(OverlayCorner::TopRight), record_settings.overlay.keys);
 		assert_eq!(
 			RecordWindow::Root(Some(Geometry::new(0, 0, 10, 10))),
 			record_settings.window
@@ -431,5 +901,112 @@ mod tests {
 		assert!(record_settings.flag.alpha);
 		assert_eq!("LControl-Q,S", record_settings.flag.action_keys.unwrap());
 		assert_eq!("X", record_settings.flag.cancel_keys.unwrap());
+		assert_eq!(Some("LAlt-M"), record_settings.flag.marker_keys);
+	}
+	#[test]
+	fn test_timelapse_settings() {
+		let args = App::new("test")
+			.arg(Arg::with_name("every").long("every").takes_value(true))
+			.arg(Arg::with_name("count").long("count").takes_value(true))
+			.arg(
+				Arg::with_name("assemble")
+					.long("assemble")
+					.takes_value(true),
+			)
+			.get_matches_from(vec![
+				"test",
+				"--every",
+				"2m",
+				"--count",
+				"5",
+				"--assemble",
+				"apng",
+			]);
+		let timelapse_settings =
+			TimelapseSettings::from_parser(ArgParser::from_args(&args));
+		assert!(timelapse_settings.is_enabled());
+		assert_eq!(Some(120.), timelapse_settings.every);
+		assert_eq!(Some(5), timelapse_settings.count);
+		assert_eq!(Some(AnimFormat::Apng), timelapse_settings.format);
+		assert!(!TimelapseSettings::from_parser(ArgParser::new(None)).is_enabled());
+		assert_eq!(Some(0.5), RecordTime::parse_duration("500ms"));
+		assert_eq!(Some(10.), RecordTime::parse_duration("10s"));
+		assert_eq!(Some(7200.), RecordTime::parse_duration("2h"));
+		assert_eq!(Some(1.5), RecordTime::parse_duration("1.5"));
+		assert_eq!(None, RecordTime::parse_duration("-1s"));
+		assert_eq!(None, RecordTime::parse_duration("x"));
+	}
+	#[test]
+	fn test_step_settings() {
+		let args = App::new("test")
+			.arg(
+				Arg::with_name("steps")
+					.long("steps")
+					.min_values(0)
+					.require_equals(true),
+			)
+			.arg(
+				Arg::with_name("step-dir")
+					.long("step-dir")
+					.takes_value(true),
+			)
+			.get_matches_from(vec!["test", "--steps", "--step-dir", "steps"]);
+		let step_settings = StepSettings::from_parser(ArgParser::from_args(&args));
+		assert!(step_settings.is_enabled());
+		assert_eq!(Some(2.), step_settings.hold);
+		assert_eq!(Some("steps"), step_settings.dir);
+		assert!(!StepSettings::from_parser(ArgParser::new(None)).is_enabled());
+	}
+	#[test]
+	fn test_trigger_settings() {
+		assert_eq!(
+			Some(StartTrigger::Window("Test: Run")),
+			StartTrigger::parse("window:Test: Run")
+		);
+		assert_eq!(Some(StartTrigger::Change), StartTrigger::parse("change"));
+		assert_eq!(None, StartTrigger::parse("window:"));
+		assert_eq!(Some(StopTrigger::Close), StopTrigger::parse("close"));
+		assert_eq!(Some(StopTrigger::Idle(120.)), StopTrigger::parse("idle:2m"));
+		assert_eq!(
+			Some(StopTrigger::File("/tmp/done")),
+			StopTrigger::parse("file:/tmp/done")
+		);
+		assert_eq!(None, StopTrigger::parse("idle"));
+		let args = App::new("test")
+			.arg(
+				Arg::with_name("start-on")
+					.long("start-on")
+					.takes_value(true),
+			)
+			.arg(Arg::with_name("stop-on").long("stop-on").takes_value(true))
+			.get_matches_from(vec![
+				"test",
+				"--start-on",
+				"change",
+				"--stop-on",
+				"x",
+			]);
+		assert_eq!(
+			TriggerSettings::new(Some(StartTrigger::Change), None),
+			TriggerSettings::from_args(&ArgMatches::new(&args))
+		);
+	}
+	#[test]
+	fn test_multi_record_settings() {
+		let args = App::new("test")
+			.arg(Arg::with_name("windows").long("windows").takes_value(true))
+			.arg(Arg::with_name("compose").long("compose"))
+			.get_matches_from(vec![
+				"test",
+				"--windows",
+				"0x1e00004, xterm,",
+				"--compose",
+			]);
+		let multi_settings =
+			MultiRecordSettings::from_parser(ArgParser::from_args(&args));
+		assert!(multi_settings.is_enabled());
+		assert!(multi_settings.compose);
+		assert_eq!(vec!["0x1e00004", "xterm"], multi_settings.windows);
+		assert!(!MultiRecordSettings::from_parser(ArgParser::new(None)).is_enabled());
 	}
 }
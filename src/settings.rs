@@ -1,12 +1,17 @@
 use crate::analyze::settings::AnalyzeSettings;
+use crate::anim::preset::AnimPreset;
 use crate::anim::settings::{AnimSettings, SplitSettings};
 use crate::args::matches::ArgMatches;
+use crate::args::parser::ArgParser;
 use crate::edit::settings::EditSettings;
 use crate::file::format::FileFormat;
 use crate::file::settings::SaveSettings;
-use crate::image::geometry::Geometry;
-use crate::image::settings::{JpgSettings, PngSettings, PnmSettings};
-use crate::record::settings::{RecordSettings, RecordWindow};
+use crate::image::settings::{IcoSettings, JpgSettings, PngSettings, PnmSettings};
+use crate::record::settings::{
+	MultiRecordSettings, RecordSettings, StepSettings, TimelapseSettings,
+};
+use crate::tiff::settings::TiffSettings;
+use crate::tty::settings::{CastSettings, TtySettings};
 use crate::util::keys::{ActionKeys, KeyType};
 use crate::util::state::InputState;
 use crate::view::settings::ViewSettings;
@@ -17,11 +22,18 @@ use colored::Color;
 pub struct AppSettings<'a> {
 	pub args: &'a ArgMatches<'a>,
 	pub record: RecordSettings,
+	pub timelapse: TimelapseSettings,
+	pub steps: StepSettings,
+	pub multi: MultiRecordSettings,
+	pub tty: TtySettings,
+	pub cast: CastSettings,
 	pub anim: AnimSettings,
 	pub split: SplitSettings,
 	pub png: PngSettings,
 	pub jpg: JpgSettings,
 	pub pnm: PnmSettings,
+	pub ico: IcoSettings,
+	pub tiff: TiffSettings,
 	pub edit: EditSettings,
 	pub analyze: AnalyzeSettings,
 	pub view: ViewSettings,
@@ -38,20 +50,30 @@ impl<'a> AppSettings<'a> {
 	 * @return AppSettings
 	 */
 	pub fn new(args: &'a ArgMatches<'a>) -> Self {
-		let window_required =
-			args.is_present("record") || args.is_present("capture");
+		let tty = TtySettings::from_args(args);
+		let window_required = (args.is_present("record") && !tty.enabled)
+			|| args.is_present("capture");
 		let record = RecordSettings::from_args(args);
 		let pnm = PnmSettings::from_args(args);
-		let edit = EditSettings::from_args(args);
+		let mut edit = EditSettings::from_args(args);
 		let save = SaveSettings::from_args(args, &edit, &pnm);
+		let mut anim = AnimSettings::from_args(args, &save.file.format);
+		Self::apply_preset(args, &save.file.format, &mut anim, &mut edit);
 		let input_state = Self::get_input_state(window_required, &record);
 		Self {
 			args,
 			record,
-			anim: AnimSettings::from_args(args, &save.file.format),
+			timelapse: TimelapseSettings::from_args(args),
+			steps: StepSettings::from_args(args),
+			multi: MultiRecordSettings::from_args(args),
+			tty,
+			cast: CastSettings::from_args(args),
+			anim,
 			split: SplitSettings::from_args(args),
 			png: PngSettings::from_args(args),
 			jpg: JpgSettings::from_args(args),
+			ico: IcoSettings::from_args(args),
+			tiff: TiffSettings::from_args(args),
 			analyze: AnalyzeSettings::from_args(args, Self::get_color(args)),
 			view: ViewSettings::from_args(args),
 			pnm,
@@ -62,6 +84,35 @@ impl<'a> AppSettings<'a> {
 		}
 	}
 
+	/**
+	 * Apply the selected encoding preset to the settings.
+	 *
+	 * @param  args
+	 * @param  file_format
+	 * @param  anim
+	 * @param  edit
+	 */
+	fn apply_preset(
+		args: &'a ArgMatches<'a>,
+		file_format: &FileFormat,
+		anim: &mut AnimSettings,
+		edit: &mut EditSettings,
+	) {
+		if let Some(preset) = anim
+			.preset
+			.as_deref()
+			.and_then(|name| AnimPreset::get(name, args.config.as_ref()))
+		{
+			let section = AnimSettings::get_section(args, file_format);
+			preset.apply(
+				ArgParser::from_subcommand(args, &section).args.as_ref(),
+				ArgParser::from_subcommand(args, "edit").args.as_ref(),
+				anim,
+				edit,
+			);
+		}
+	}
+
 	/**
 	 * Get the main color from parsed arguments. (exposed)
 	 *
@@ -103,7 +154,7 @@ impl<'a> AppSettings<'a> {
 		window_required: bool,
 		record: &RecordSettings,
 	) -> Option<&'static InputState> {
-		if window_required {
+		if window_required && record.virtual_size.is_none() {
 			Some(Box::leak(
 				InputState::new(
 					if let Some(keys) = record.flag.action_keys {
@@ -116,6 +167,10 @@ impl<'a> AppSettings<'a> {
 					} else {
 						ActionKeys::default(KeyType::CancelKeys)
 					},
+					record
+						.flag
+						.marker_keys
+						.map(|keys| ActionKeys::parse(keys, KeyType::MarkerKeys)),
 					record.flag.mouse,
 				)
 				.into_boxed_state(),
@@ -131,6 +186,12 @@ impl<'a> AppSettings<'a> {
 		if self.jpg.quality <= 25 {
 			warn!("Image will be encoded in low quality.")
 		}
+		if let Some(name) = &self.anim.preset {
+			match AnimPreset::get(name, self.args.config.as_ref()) {
+				Some(preset) => debug!("Using the preset: {:?}", preset),
+				None => warn!("Preset not found: {}", name),
+			}
+		}
 		if self.anim.quality <= 20 {
 			warn!("Animation will be encoded in low quality.")
 		}
@@ -155,41 +216,21 @@ impl<'a> AppSettings<'a> {
 					input_state.cancel_keys.to_string()
 				);
 			}
+			if let Some(marker_keys) = &input_state.marker_keys {
+				info!("Using marker keys: {}", marker_keys.to_string());
+			}
 		}
 		if !self.record.flag.select {
 			self.record.border = None;
 		}
-		if self.save.file.format == FileFormat::Ico {
-			self.set_icon_size()
-		}
-	}
-
-	/* Set the area size to 256x256 for encoding ICO. */
-	fn set_icon_size(&mut self) {
-		let ico_geometry = Geometry::new(0, 0, 256, 256);
-		match self.record.window {
-			RecordWindow::Focus(None, parent) => {
-				self.record.window = RecordWindow::Focus(Some(ico_geometry), parent)
-			}
-			RecordWindow::Root(None) => {
-				self.record.window = RecordWindow::Root(Some(ico_geometry))
-			}
-			RecordWindow::Focus(Some(ref mut geometry), _)
-			| RecordWindow::Root(Some(ref mut geometry)) => {
-				if geometry.width == 0 || geometry.width > ico_geometry.width {
-					geometry.width = ico_geometry.width;
-				}
-				if geometry.height == 0 || geometry.height > ico_geometry.height {
-					geometry.height = ico_geometry.height;
-				}
-			}
-		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::image::geometry::Geometry;
+	use crate::record::settings::RecordWindow;
 	use clap::ArgMatches as Args;
 	use pretty_assertions::assert_eq;
 	use std::env;
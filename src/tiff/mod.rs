@@ -0,0 +1,220 @@
+pub mod settings;
+
+use crate::app::AppResult;
+use crate::image::Image;
+use crate::tiff::settings::{TiffCompression, TiffSettings};
+use crate::util::state::InputState;
+use image::ExtendedColorType;
+use miniz_oxide::deflate::compress_to_vec_zlib;
+use std::io::{self, Cursor, Seek, Write};
+use tiff::encoder::TiffEncoder as Encoder;
+use tiff::tags::Tag;
+use weezl::encode::Encoder as LzwEncoder;
+use weezl::BitOrder;
+
+/* Multi-page TIFF encoder and settings */
+pub struct TiffEncoder<Output: Write> {
+	output: Output,
+	settings: TiffSettings,
+}
+
+impl<Output: Write> TiffEncoder<Output> {
+	/**
+	 * Create a new TiffEncoder object.
+	 *
+	 * @param  output
+	 * @param  settings
+	 * @return TiffEncoder
+	 */
+	pub fn new(output: Output, settings: TiffSettings) -> Self {
+		Self { output, settings }
+	}
+
+	/**
+	 * Encode images as pages and write to the TIFF file.
+	 *
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	pub fn save(
+		mut self,
+		images: Vec<Image>,
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		let mut data = Cursor::new(Vec::new());
+		let mut encoder = Encoder::new(&mut data)?;
+		for (i, image) in images.iter().enumerate() {
+			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
+			info!("Saving... ({:.1}%)\r", percentage);
+			debug!(
+				"Encoding... ({:.1}%) [{}/{}]\r",
+				percentage,
+				i + 1,
+				images.len()
+			);
+			io::stdout().flush()?;
+			if let Some(state) = input_state {
+				if state.check_cancel_keys() {
+					info!("\n");
+					warn!("User interrupt detected.");
+					panic!("Failed to write the frames")
+				}
+			}
+			self.write_page(&mut encoder, image)?;
+		}
+		self.output.write_all(data.get_ref())?;
+		info!("\n");
+		Ok(())
+	}
+
+	/**
+	 * Write the image as a new page (directory) with a single strip.
+	 *
+	 * @param  encoder
+	 * @param  image
+	 * @return Result
+	 */
+	fn write_page<W: Write + Seek>(
+		&self,
+		encoder: &mut Encoder<W>,
+		image: &Image,
+	) -> AppResult<()> {
+		let (width, height) = (image.geometry.width, image.geometry.height);
+		let data = self.compress(
+			&image.get_data(ExtendedColorType::Rgba8),
+			width as usize * 4,
+		)?;
+		let mut directory = encoder.new_directory()?;
+		let offset = directory.write_data(data.as_slice())?;
+		if data.len() % 2 == 1 {
+			directory.write_data(0u8)?;
+		}
+		directory.write_tag(Tag::ImageWidth, width)?;
+		directory.write_tag(Tag::ImageLength, height)?;
+		directory.write_tag(Tag::BitsPerSample, &[8u16, 8, 8, 8][..])?;
+		directory.write_tag(
+			Tag::Compression,
+			self.settings.compression.get_tag_value(),
+		)?;
+		directory.write_tag(Tag::PhotometricInterpretation, 2u16)?;
+		directory.write_tag(Tag::StripOffsets, offset as u32)?;
+		directory.write_tag(Tag::SamplesPerPixel, 4u16)?;
+		directory.write_tag(Tag::RowsPerStrip, height)?;
+		directory.write_tag(Tag::StripByteCounts, data.len() as u32)?;
+		directory.write_tag(Tag::PlanarConfiguration, 1u16)?;
+		directory.write_tag(Tag::ExtraSamples, 2u16)?;
+		directory.finish()?;
+		Ok(())
+	}
+
+	/**
+	 * Compress the image data with the selected method.
+	 *
+	 * @param  data
+	 * @param  row_size
+	 * @return Vector of u8 (Result)
+	 */
+	fn compress(&self, data: &[u8], row_size: usize) -> AppResult<Vec<u8>> {
+		Ok(match self.settings.compression {
+			TiffCompression::None => data.to_vec(),
+			TiffCompression::Lzw => {
+				LzwEncoder::with_tiff_size_switch(BitOrder::Msb, 8)
+					.encode(data)
+					.map_err(|e| {
+						io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+					})?
+			}
+			TiffCompression::Deflate => compress_to_vec_zlib(data, 6),
+			TiffCompression::PackBits => data
+				.chunks(row_size.max(1))
+				.flat_map(Self::pack_bits)
+				.collect(),
+		})
+	}
+
+	/**
+	 * Compress a row using the PackBits (run-length) scheme.
+	 *
+	 * @param  row
+	 * @return Vector of u8
+	 */
+	fn pack_bits(row: &[u8]) -> Vec<u8> {
+		let mut packed = Vec::new();
+		let mut i = 0;
+		while i < row.len() {
+			let mut run = 1;
+			while i + run < row.len() && run < 128 && row[i + run] == row[i] {
+				run += 1;
+			}
+			if run > 1 {
+				packed.push((257 - run) as u8);
+				packed.push(row[i]);
+				i += run;
+			} else {
+				let start = i;
+				while i < row.len()
+					&& i - start < 128
+					&& (i + 1 == row.len() || row[i] != row[i + 1])
+				{
+					i += 1;
+				}
+				packed.push((i - start - 1) as u8);
+				packed.extend(&row[start..i]);
+			}
+		}
+		packed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::Bgra;
+	use miniz_oxide::inflate::decompress_to_vec_zlib;
+	use pretty_assertions::assert_eq;
+	use tiff::decoder::{Decoder, DecodingResult};
+	#[test]
+	fn test_tiff_encoder() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 3, 2);
+		let images = vec![
+			Image::new(vec![Bgra::from([255, 0, 0, 255]); 6], false, geometry),
+			Image::new(vec![Bgra::from([0, 255, 0, 128]); 6], true, geometry),
+		];
+		for compression in [TiffCompression::Lzw, TiffCompression::PackBits] {
+			let mut output = Vec::new();
+			TiffEncoder::new(&mut output, TiffSettings::new(compression))
+				.save(images.clone(), None)?;
+			let mut decoder = Decoder::new(Cursor::new(output))?;
+			let mut pages = Vec::new();
+			loop {
+				assert_eq!((3, 2), decoder.dimensions()?);
+				if let DecodingResult::U8(data) = decoder.read_image()? {
+					pages.push(data[..4].to_vec());
+				}
+				if !decoder.more_images() {
+					break;
+				}
+				decoder.next_image()?;
+			}
+			assert_eq!(vec![vec![0, 0, 255, 255], vec![0, 255, 0, 128]], pages);
+		}
+		let encoder = TiffEncoder::new(
+			Vec::new(),
+			TiffSettings::new(TiffCompression::Deflate),
+		);
+		let data = images[0].get_data(ExtendedColorType::Rgba8);
+		assert_eq!(
+			Ok(data.clone()),
+			decompress_to_vec_zlib(&encoder.compress(&data, 12)?)
+		);
+		assert_eq!(
+			vec![0xFE, 0xAA, 0x02, 0x80, 0x00, 0x2A, 0xFD, 0xAA, 0x00, 0x22],
+			TiffEncoder::<Vec<u8>>::pack_bits(&[
+				0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0xAA, 0xAA, 0xAA, 0xAA, 0x22
+			])
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,126 @@
+use crate::args::matches::ArgMatches;
+use crate::args::parser::ArgParser;
+
+/* TIFF compression methods */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TiffCompression {
+	None,
+	Lzw,
+	Deflate,
+	PackBits,
+}
+
+impl TiffCompression {
+	/**
+	 * Parse TiffCompression from a string.
+	 *
+	 * @param  compression
+	 * @return TiffCompression
+	 */
+	pub fn parse(compression: &str) -> Self {
+		match compression {
+			"lzw" => Self::Lzw,
+			"deflate" => Self::Deflate,
+			"packbits" => Self::PackBits,
+			_ => Self::None,
+		}
+	}
+
+	/**
+	 * Get the value of the compression tag.
+	 *
+	 * @return u16
+	 */
+	pub fn get_tag_value(&self) -> u16 {
+		match self {
+			Self::None => 1,
+			Self::Lzw => 5,
+			Self::Deflate => 8,
+			Self::PackBits => 32773,
+		}
+	}
+}
+
+/* TIFF compression settings */
+#[derive(Clone, Copy, Debug)]
+pub struct TiffSettings {
+	pub compression: TiffCompression,
+}
+
+/* Default initialization values for TiffSettings */
+impl Default for TiffSettings {
+	fn default() -> Self {
+		Self {
+			compression: TiffCompression::None,
+		}
+	}
+}
+
+impl TiffSettings {
+	/**
+	 * Create a new TiffSettings object.
+	 *
+	 * @param  compression
+	 * @return TiffSettings
+	 */
+	pub fn new(compression: TiffCompression) -> Self {
+		Self { compression }
+	}
+
+	/**
+	 * Create a new TiffSettings object from arguments.
+	 *
+	 * @param  matches
+	 * @return TiffSettings
+	 */
+	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(
+			matches,
+			if matches.is_present("make") {
+				"make"
+			} else {
+				"tiff"
+			},
+		))
+	}
+
+	/**
+	 * Create a TiffSettings object from an argument parser.
+	 *
+	 * @param  parser
+	 * @return TiffSettings
+	 */
+	fn from_parser(parser: ArgParser<'_>) -> Self {
+		match parser.args {
+			Some(matches) => Self::new(TiffCompression::parse(
+				matches.value_of("compression").unwrap_or_default(),
+			)),
+			None => Self::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use clap::{App, Arg};
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_tiff_settings() {
+		let args = App::new("test")
+			.arg(
+				Arg::with_name("compression")
+					.long("compression")
+					.takes_value(true),
+			)
+			.get_matches_from(vec!["test", "--compression", "packbits"]);
+		let settings = TiffSettings::from_parser(ArgParser::from_args(&args));
+		assert_eq!(TiffCompression::PackBits, settings.compression);
+		assert_eq!(32773, settings.compression.get_tag_value());
+		assert_eq!(TiffCompression::None, TiffCompression::parse("fast"));
+		assert_eq!(
+			TiffCompression::None,
+			TiffSettings::from_parser(ArgParser::new(None)).compression
+		);
+	}
+}
@@ -0,0 +1,244 @@
+use crate::image::font::BitmapFont;
+use crate::image::Image;
+use crate::tty::settings::{CastSettings, TtyTheme};
+use crate::tty::terminal::Terminal;
+use serde_json::Value;
+use std::io::{self, BufRead, Error, ErrorKind};
+
+/* Supported version of the asciicast format */
+const CAST_VERSION: u64 = 2;
+
+/* Event of an asciicast recording */
+#[derive(Clone, Debug, PartialEq)]
+pub enum CastEvent {
+	Output(f64, String),
+	Marker(f64),
+}
+
+impl CastEvent {
+	/**
+	 * Get the time of the event in seconds.
+	 *
+	 * @return f64
+	 */
+	pub fn get_time(&self) -> f64 {
+		match self {
+			Self::Output(time, _) | Self::Marker(time) => *time,
+		}
+	}
+}
+
+/* Terminal recording in asciicast v2 format */
+#[derive(Clone, Debug)]
+pub struct AsciiCast {
+	pub width: usize,
+	pub height: usize,
+	pub idle_time_limit: Option<f64>,
+	pub theme: Option<TtyTheme>,
+	pub events: Vec<CastEvent>,
+}
+
+impl AsciiCast {
+	/**
+	 * Parse an asciicast recording from the given input.
+	 *
+	 * @param  input
+	 * @return AsciiCast (Result)
+	 */
+	pub fn parse<Input: BufRead>(input: Input) -> io::Result<Self> {
+		let mut lines = input.lines().filter(|line| match line {
+			Ok(line) => !line.trim().is_empty(),
+			Err(_) => true,
+		});
+		let header = Self::parse_json(
+			&lines
+				.next()
+				.ok_or_else(|| Self::get_error("Missing header"))??,
+		)?;
+		if header["version"].as_u64() != Some(CAST_VERSION) {
+			return Err(Self::get_error("Unsupported version"));
+		}
+		let mut cast = Self {
+			width: header["width"]
+				.as_u64()
+				.ok_or_else(|| Self::get_error("Missing width"))? as usize,
+			height: header["height"]
+				.as_u64()
+				.ok_or_else(|| Self::get_error("Missing height"))? as usize,
+			idle_time_limit: header["idle_time_limit"].as_f64(),
+			theme: TtyTheme::from_colors(
+				header["theme"]["fg"].as_str().unwrap_or_default(),
+				header["theme"]["bg"].as_str().unwrap_or_default(),
+				&header["theme"]["palette"]
+					.as_str()
+					.unwrap_or_default()
+					.split(':')
+					.collect::<Vec<&str>>(),
+			),
+			events: Vec::new(),
+		};
+		for line in lines {
+			let event = Self::parse_json(&line?)?;
+			let time = event[0]
+				.as_f64()
+				.ok_or_else(|| Self::get_error("Invalid event time"))?;
+			match (event[1].as_str(), event[2].as_str()) {
+				(Some("o"), Some(data)) => {
+					cast.events.push(CastEvent::Output(time, data.to_string()))
+				}
+				(Some("m"), _) => cast.events.push(CastEvent::Marker(time)),
+				_ => trace!("Skipping the event: {}", event),
+			}
+		}
+		Ok(cast)
+	}
+
+	/**
+	 * Parse the given line as JSON.
+	 *
+	 * @param  line
+	 * @return Value (Result)
+	 */
+	fn parse_json(line: &str) -> io::Result<Value> {
+		serde_json::from_str(line)
+			.map_err(|e| Self::get_error(&format!("Invalid JSON: {}", e)))
+	}
+
+	/**
+	 * Create an error for invalid cast data.
+	 *
+	 * @param  message
+	 * @return Error
+	 */
+	fn get_error(message: &str) -> Error {
+		Error::new(ErrorKind::InvalidData, format!("asciicast: {}", message))
+	}
+}
+
+/* Renderer for replaying asciicast recordings as frames */
+#[derive(Debug)]
+pub struct CastRenderer<'a> {
+	settings: &'a CastSettings,
+	fps: u32,
+}
+
+impl<'a> CastRenderer<'a> {
+	/**
+	 * Create a new CastRenderer object.
+	 *
+	 * @param  settings
+	 * @param  fps
+	 * @return CastRenderer
+	 */
+	pub fn new(settings: &'a CastSettings, fps: u32) -> Self {
+		Self {
+			settings,
+			fps: fps.max(1),
+		}
+	}
+
+	/**
+	 * Get the playback times of events after limiting the idle time.
+	 *
+	 * @param  cast
+	 * @return Vector of f64
+	 */
+	fn get_times(&self, cast: &AsciiCast) -> Vec<f64> {
+		let idle_limit = self
+			.settings
+			.idle_limit
+			.or(cast.idle_time_limit)
+			.unwrap_or(f64::INFINITY);
+		let (mut previous, mut elapsed) = (0., 0.);
+		cast.events
+			.iter()
+			.map(|event| {
+				let time = event.get_time();
+				elapsed += (time - previous).clamp(0., idle_limit);
+				previous = time;
+				elapsed / self.settings.speed as f64
+			})
+			.collect()
+	}
+
+	/**
+	 * Replay the events and render the frames with their timing.
+	 *
+	 * @param  cast
+	 * @return Vector of Image
+	 */
+	pub fn render(&self, cast: &AsciiCast) -> Vec<Image> {
+		let theme = self.settings.theme.or(cast.theme).unwrap_or_default();
+		let font = BitmapFont::new(self.settings.scale);
+		let mut terminal = Terminal::new(cast.width, cast.height);
+		let times = self.get_times(cast);
+		let frame_duration = 1. / self.fps as f64;
+		let mut frames = Vec::new();
+		if !matches!(times.first(), Some(time) if *time <= 0.) {
+			frames.push((0., terminal.render(&font, &theme)));
+		}
+		let mut i = 0;
+		while i < cast.events.len() {
+			let start = times[i];
+			let mut marker = false;
+			while i < cast.events.len() && times[i] < start + frame_duration {
+				match &cast.events[i] {
+					CastEvent::Output(_, data) => terminal.feed(data.as_bytes()),
+					CastEvent::Marker(_) => marker = true,
+				}
+				i += 1;
+			}
+			terminal.take_response();
+			let mut image = terminal.render(&font, &theme);
+			image.marker = marker;
+			frames.push((start, image));
+		}
+		let end_times = frames
+			.iter()
+			.skip(1)
+			.map(|(time, _)| *time)
+			.chain(frames.last().map(|(time, _)| time + frame_duration))
+			.collect::<Vec<f64>>();
+		frames
+			.into_iter()
+			.zip(end_times)
+			.map(|((start, mut image), end)| {
+				image.delay = Some(((end - start) * 1e3).round() as u32);
+				image
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_asciicast() -> io::Result<()> {
+		let cast = AsciiCast::parse(
+			r#"{"version": 2, "width": 10, "height": 2, "idle_time_limit": 1.0}
+[0.5, "o", "menyoki"]
+[0.52, "o", "\r\n"]
+[4.5, "m", ""]
+[4.5, "i", "q"]
+[5.0, "o", "\u001b[31mexit"]
+"#
+			.as_bytes(),
+		)?;
+		assert_eq!((10, 2), (cast.width, cast.height));
+		assert_eq!(4, cast.events.len());
+		assert_eq!(CastEvent::Marker(4.5), cast.events[2]);
+		let settings = CastSettings::new(None, None, 2., 1, None);
+		let renderer = CastRenderer::new(&settings, 10);
+		assert_eq!(vec![0.25, 0.26, 0.76, 1.01], renderer.get_times(&cast));
+		let frames = renderer.render(&cast);
+		assert_eq!(
+			vec![Some(250), Some(510), Some(250), Some(100)],
+			frames.iter().map(|frame| frame.delay).collect::<Vec<_>>()
+		);
+		assert!(frames[2].marker);
+		assert!(AsciiCast::parse(r#"{"version": 1}"#.as_bytes()).is_err());
+		Ok(())
+	}
+}
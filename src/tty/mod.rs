@@ -0,0 +1,137 @@
+pub mod cast;
+#[cfg(unix)]
+pub mod pty;
+pub mod settings;
+pub mod terminal;
+
+use crate::image::font::BitmapFont;
+use crate::image::Image;
+use crate::record::fps::FpsClock;
+use crate::tty::settings::TtySettings;
+use crate::tty::terminal::Terminal;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::TryRecvError;
+use std::time::Instant;
+
+/* Recorder for rendering the terminal output of a command */
+#[derive(Debug)]
+pub struct TtyRecorder {
+	settings: TtySettings,
+	clock: FpsClock,
+	duration: Option<f64>,
+}
+
+impl TtyRecorder {
+	/**
+	 * Create a new TtyRecorder object.
+	 *
+	 * @param  settings
+	 * @param  fps
+	 * @param  duration (Option)
+	 * @return TtyRecorder
+	 */
+	pub fn new(settings: TtySettings, fps: u32, duration: Option<f64>) -> Self {
+		Self {
+			settings,
+			clock: FpsClock::new(fps),
+			duration,
+		}
+	}
+
+	/**
+	 * Run the command in a pseudo-terminal and render its output as frames.
+	 *
+	 * @param  command
+	 * @param  recording
+	 * @return Vector of Image (Result)
+	 */
+	#[cfg(unix)]
+	pub fn record(
+		mut self,
+		command: &str,
+		recording: &AtomicBool,
+	) -> io::Result<Vec<Image>> {
+		let mut pty =
+			pty::Pty::spawn(command, self.settings.cols, self.settings.rows)?;
+		let receiver = pty.read_async()?;
+		let mut terminal = Terminal::new(self.settings.cols, self.settings.rows);
+		let font = BitmapFont::new(self.settings.scale);
+		let mut frames = Vec::new();
+		let start = Instant::now();
+		info!(
+			"Recording the terminal [{}x{}] at {} FPS...",
+			self.settings.cols, self.settings.rows, self.clock.fps
+		);
+		while recording.load(Ordering::SeqCst) {
+			self.clock.tick();
+			let (mut received, mut closed) = (false, false);
+			loop {
+				match receiver.try_recv() {
+					Ok(data) => {
+						terminal.feed(&data);
+						received = true;
+					}
+					Err(TryRecvError::Empty) => break,
+					Err(TryRecvError::Disconnected) => {
+						closed = true;
+						break;
+					}
+				}
+			}
+			let response = terminal.take_response();
+			if !response.is_empty() {
+				pty.write(&response)?;
+			}
+			frames.push(terminal.render(&font, &self.settings.theme));
+			debug!("Frames: {}\r", frames.len());
+			io::stdout().flush()?;
+			if closed
+				|| (!received && pty.has_exited()?)
+				|| matches!(self.duration,
+					Some(duration) if start.elapsed().as_secs_f64() >= duration)
+			{
+				break;
+			}
+		}
+		debug!("\n");
+		Ok(frames)
+	}
+
+	/**
+	 * Terminal recording is only supported on Unix-like systems.
+	 *
+	 * @param  command
+	 * @param  recording
+	 * @return Vector of Image (Result)
+	 */
+	#[cfg(not(unix))]
+	pub fn record(
+		self,
+		_command: &str,
+		_recording: &AtomicBool,
+	) -> io::Result<Vec<Image>> {
+		Err(io::Error::new(
+			io::ErrorKind::Other,
+			"Terminal recording is not supported on this platform",
+		))
+	}
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod tests {
+	use super::*;
+	use crate::tty::settings::TtySettings;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_tty_recorder() -> io::Result<()> {
+		let settings = TtySettings::new(true, 20, 4, 1, Default::default());
+		let recording = AtomicBool::new(true);
+		let frames = TtyRecorder::new(settings, 20, Some(5.))
+			.record("printf 'menyoki\\n'", &recording)?;
+		assert!(!frames.is_empty());
+		assert_eq!(132, frames[0].geometry.width);
+		Ok(())
+	}
+}
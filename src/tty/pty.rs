@@ -0,0 +1,128 @@
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, Stdio};
+use std::ptr;
+use std::sync::mpsc;
+use std::thread;
+
+/* Size of the buffer for reading the terminal output */
+const READ_BUFFER_SIZE: usize = 4096;
+
+/* Command that runs in a pseudo-terminal */
+#[derive(Debug)]
+pub struct Pty {
+	master: File,
+	child: Child,
+}
+
+impl Pty {
+	/**
+	 * Run the command in a new pseudo-terminal with the given size.
+	 *
+	 * @param  command
+	 * @param  cols
+	 * @param  rows
+	 * @return Pty (Result)
+	 */
+	pub fn spawn(command: &str, cols: usize, rows: usize) -> io::Result<Self> {
+		let (mut master, mut slave) = (0, 0);
+		let size = libc::winsize {
+			ws_row: rows as u16,
+			ws_col: cols as u16,
+			ws_xpixel: 0,
+			ws_ypixel: 0,
+		};
+		if unsafe {
+			libc::openpty(
+				&mut master,
+				&mut slave,
+				ptr::null_mut(),
+				ptr::null(),
+				&size,
+			)
+		} != 0
+		{
+			return Err(io::Error::last_os_error());
+		}
+		let (master, slave) =
+			unsafe { (File::from_raw_fd(master), File::from_raw_fd(slave)) };
+		if unsafe {
+			libc::fcntl(master.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC)
+		} == -1
+		{
+			return Err(io::Error::last_os_error());
+		}
+		let mut process = Command::new("sh");
+		process
+			.args(["-c", command])
+			.env("TERM", "xterm-256color")
+			.env("COLUMNS", cols.to_string())
+			.env("LINES", rows.to_string())
+			.stdin(Stdio::from(slave.try_clone()?))
+			.stdout(Stdio::from(slave.try_clone()?))
+			.stderr(Stdio::from(slave));
+		unsafe {
+			process.pre_exec(|| {
+				if libc::setsid() == -1
+					|| libc::ioctl(0, libc::TIOCSCTTY as _, 0) == -1
+				{
+					return Err(io::Error::last_os_error());
+				}
+				Ok(())
+			});
+		}
+		let child = process.spawn()?;
+		Ok(Self { master, child })
+	}
+
+	/**
+	 * Read the terminal output in a separate thread.
+	 *
+	 * @return Receiver (Result)
+	 */
+	pub fn read_async(&self) -> io::Result<mpsc::Receiver<Vec<u8>>> {
+		let mut master = self.master.try_clone()?;
+		let (sender, receiver) = mpsc::channel();
+		thread::spawn(move || {
+			let mut buffer = [0; READ_BUFFER_SIZE];
+			while let Ok(size) = master.read(&mut buffer) {
+				if size == 0 || sender.send(buffer[..size].to_vec()).is_err() {
+					break;
+				}
+			}
+		});
+		Ok(receiver)
+	}
+
+	/**
+	 * Write the given input to the terminal.
+	 *
+	 * @param  input
+	 * @return Result
+	 */
+	pub fn write(&mut self, input: &[u8]) -> io::Result<()> {
+		self.master.write_all(input)
+	}
+
+	/**
+	 * Check if the command has exited.
+	 *
+	 * @return bool (Result)
+	 */
+	pub fn has_exited(&mut self) -> io::Result<bool> {
+		Ok(self.child.try_wait()?.is_some())
+	}
+}
+
+/* Stop the command if it is still running */
+impl Drop for Pty {
+	fn drop(&mut self) {
+		if let Ok(None) = self.child.try_wait() {
+			if let Err(e) = self.child.kill().and_then(|_| self.child.wait()) {
+				error!("Failed to stop the command: {}", e);
+			}
+		}
+	}
+}
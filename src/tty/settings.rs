@@ -0,0 +1,474 @@
+use crate::args::matches::ArgMatches;
+use crate::args::parser::ArgParser;
+use crate::image::geometry::Geometry;
+use crate::tty::terminal::{CellColor, CellStyle};
+use image::Bgra;
+use std::path::PathBuf;
+
+/* Levels of the 6x6x6 color cube in the 256-color palette */
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/**
+ * Create a color from RGB values.
+ *
+ * @param  r
+ * @param  g
+ * @param  b
+ * @return Bgra
+ */
+const fn rgb(r: u8, g: u8, b: u8) -> Bgra<u8> {
+	Bgra([b, g, r, 255])
+}
+
+/* Colors of the rendered terminal */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TtyTheme {
+	pub foreground: Bgra<u8>,
+	pub background: Bgra<u8>,
+	pub palette: [Bgra<u8>; 16],
+}
+
+/* Default initialization values for TtyTheme */
+impl Default for TtyTheme {
+	fn default() -> Self {
+		Self::parse("dark")
+	}
+}
+
+impl TtyTheme {
+	/**
+	 * Parse TtyTheme from a theme name.
+	 *
+	 * @param  name
+	 * @return TtyTheme
+	 */
+	pub fn parse(name: &str) -> Self {
+		match name {
+			"light" => Self {
+				foreground: rgb(51, 51, 51),
+				background: rgb(255, 255, 255),
+				palette: [
+					rgb(0, 0, 0),
+					rgb(205, 49, 49),
+					rgb(0, 188, 0),
+					rgb(148, 152, 0),
+					rgb(4, 81, 165),
+					rgb(188, 5, 188),
+					rgb(5, 152, 188),
+					rgb(85, 85, 85),
+					rgb(102, 102, 102),
+					rgb(205, 49, 49),
+					rgb(20, 206, 20),
+					rgb(181, 186, 0),
+					rgb(4, 81, 165),
+					rgb(188, 5, 188),
+					rgb(5, 152, 188),
+					rgb(165, 165, 165),
+				],
+			},
+			_ => Self {
+				foreground: rgb(220, 220, 220),
+				background: rgb(30, 30, 30),
+				palette: [
+					rgb(0, 0, 0),
+					rgb(205, 49, 49),
+					rgb(13, 188, 121),
+					rgb(229, 229, 16),
+					rgb(36, 114, 200),
+					rgb(188, 63, 188),
+					rgb(17, 168, 205),
+					rgb(229, 229, 229),
+					rgb(102, 102, 102),
+					rgb(241, 76, 76),
+					rgb(35, 209, 139),
+					rgb(245, 245, 67),
+					rgb(59, 142, 234),
+					rgb(214, 112, 214),
+					rgb(41, 184, 219),
+					rgb(255, 255, 255),
+				],
+			},
+		}
+	}
+
+	/**
+	 * Create a TtyTheme from hex colors in "#rrggbb" format.
+	 *
+	 * @param  foreground
+	 * @param  background
+	 * @param  palette
+	 * @return TtyTheme (Option)
+	 */
+	pub fn from_colors(
+		foreground: &str,
+		background: &str,
+		palette: &[&str],
+	) -> Option<Self> {
+		let parse_color = |color: &str| {
+			let rgb_values = hex::decode(color.trim_start_matches('#')).ok()?;
+			match rgb_values.as_slice() {
+				[r, g, b] => Some(rgb(*r, *g, *b)),
+				_ => None,
+			}
+		};
+		let mut theme = Self {
+			foreground: parse_color(foreground)?,
+			background: parse_color(background)?,
+			..Self::default()
+		};
+		match palette.len() {
+			8 | 16 => {
+				for (i, color) in palette.iter().enumerate() {
+					theme.palette[i] = parse_color(color)?;
+					if palette.len() == 8 {
+						theme.palette[i + 8] = theme.palette[i];
+					}
+				}
+				Some(theme)
+			}
+			_ => None,
+		}
+	}
+
+	/**
+	 * Get the color value of the given cell color.
+	 *
+	 * @param  color
+	 * @param  default
+	 * @return Bgra
+	 */
+	pub fn get_color(&self, color: CellColor, default: Bgra<u8>) -> Bgra<u8> {
+		match color {
+			CellColor::Default => default,
+			CellColor::Indexed(i) if i < 16 => self.palette[i as usize],
+			CellColor::Indexed(i) if i < 232 => {
+				let i = (i - 16) as usize;
+				rgb(
+					CUBE_LEVELS[i / 36],
+					CUBE_LEVELS[(i / 6) % 6],
+					CUBE_LEVELS[i % 6],
+				)
+			}
+			CellColor::Indexed(i) => {
+				let level = 8 + (i - 232) * 10;
+				rgb(level, level, level)
+			}
+			CellColor::Rgb(r, g, b) => rgb(r, g, b),
+		}
+	}
+
+	/**
+	 * Get the foreground and background colors of the given style.
+	 *
+	 * @param  style
+	 * @return Tuple of foreground and background
+	 */
+	pub fn get_colors(&self, style: CellStyle) -> (Bgra<u8>, Bgra<u8>) {
+		let fg = match style.fg {
+			CellColor::Indexed(i) if style.bold && i < 8 => {
+				self.palette[i as usize + 8]
+			}
+			color => self.get_color(color, self.foreground),
+		};
+		let bg = self.get_color(style.bg, self.background);
+		if style.inverse {
+			(bg, fg)
+		} else {
+			(fg, bg)
+		}
+	}
+}
+
+/* Terminal recording settings */
+#[derive(Clone, Copy, Debug)]
+pub struct TtySettings {
+	pub enabled: bool,
+	pub cols: usize,
+	pub rows: usize,
+	pub scale: u32,
+	pub theme: TtyTheme,
+}
+
+/* Default initialization values for TtySettings */
+impl Default for TtySettings {
+	fn default() -> Self {
+		Self {
+			enabled: false,
+			cols: 80,
+			rows: 24,
+			scale: 2,
+			theme: TtyTheme::default(),
+		}
+	}
+}
+
+impl TtySettings {
+	/**
+	 * Create a new TtySettings object.
+	 *
+	 * @param  enabled
+	 * @param  cols
+	 * @param  rows
+	 * @param  scale
+	 * @param  theme
+	 * @return TtySettings
+	 */
+	pub fn new(
+		enabled: bool,
+		cols: usize,
+		rows: usize,
+		scale: u32,
+		theme: TtyTheme,
+	) -> Self {
+		Self {
+			enabled,
+			cols,
+			rows,
+			scale,
+			theme,
+		}
+	}
+
+	/**
+	 * Create a new TtySettings object from arguments.
+	 *
+	 * @param  matches
+	 * @return TtySettings
+	 */
+	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(matches, "record"))
+	}
+
+	/**
+	 * Create a TtySettings object from an argument parser.
+	 *
+	 * @param  parser
+	 * @return TtySettings
+	 */
+	fn from_parser(parser: ArgParser<'_>) -> Self {
+		match parser.args {
+			Some(ref matches) => {
+				let size = Geometry::parse(
+					matches.value_of("tty-size").unwrap_or_default(),
+				);
+				Self::new(
+					matches.is_present("tty"),
+					match size.width {
+						0 => Self::default().cols,
+						cols => cols as usize,
+					},
+					match size.height {
+						0 => Self::default().rows,
+						rows => rows as usize,
+					},
+					parser.parse("tty-scale", Self::default().scale).max(1),
+					TtyTheme::parse(
+						matches.value_of("tty-theme").unwrap_or_default(),
+					),
+				)
+			}
+			None => Self::default(),
+		}
+	}
+}
+
+/* Asciicast rendering settings */
+#[derive(Clone, Debug)]
+pub struct CastSettings {
+	pub file: Option<PathBuf>,
+	pub idle_limit: Option<f64>,
+	pub speed: f32,
+	pub scale: u32,
+	pub theme: Option<TtyTheme>,
+}
+
+/* Default initialization values for CastSettings */
+impl Default for CastSettings {
+	fn default() -> Self {
+		Self {
+			file: None,
+			idle_limit: None,
+			speed: 1.,
+			scale: 2,
+			theme: None,
+		}
+	}
+}
+
+impl CastSettings {
+	/**
+	 * Create a new CastSettings object.
+	 *
+	 * @param  file (Option)
+	 * @param  idle_limit (Option)
+	 * @param  speed
+	 * @param  scale
+	 * @param  theme (Option)
+	 * @return CastSettings
+	 */
+	pub fn new(
+		file: Option<PathBuf>,
+		idle_limit: Option<f64>,
+		speed: f32,
+		scale: u32,
+		theme: Option<TtyTheme>,
+	) -> Self {
+		Self {
+			file,
+			idle_limit,
+			speed: if speed > 0. { speed } else { 1. },
+			scale,
+			theme,
+		}
+	}
+
+	/**
+	 * Create a new CastSettings object from arguments.
+	 *
+	 * @param  matches
+	 * @return CastSettings
+	 */
+	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(matches, "make"))
+	}
+
+	/**
+	 * Create a CastSettings object from an argument parser.
+	 *
+	 * @param  parser
+	 * @return CastSettings
+	 */
+	fn from_parser(parser: ArgParser<'_>) -> Self {
+		match parser.args {
+			Some(ref matches) => Self::new(
+				matches.value_of("cast").map(PathBuf::from),
+				matches
+					.value_of("idle-limit")
+					.and_then(|limit| limit.parse().ok())
+					.filter(|limit| *limit > 0.),
+				parser.parse("cast-speed", Self::default().speed),
+				parser.parse("cast-scale", Self::default().scale).max(1),
+				match matches.value_of("cast-theme") {
+					Some("cast") | None => None,
+					Some(theme) => Some(TtyTheme::parse(theme)),
+				},
+			),
+			None => Self::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use clap::{App, Arg};
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_tty_settings() {
+		let args = App::new("test")
+			.arg(Arg::with_name("tty").long("tty"))
+			.arg(
+				Arg::with_name("tty-size")
+					.long("tty-size")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("tty-scale")
+					.long("tty-scale")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("tty-theme")
+					.long("tty-theme")
+					.takes_value(true),
+			)
+			.get_matches_from(vec![
+				"test",
+				"--tty",
+				"--tty-size",
+				"100x30",
+				"--tty-scale",
+				"1",
+				"--tty-theme",
+				"light",
+			]);
+		let tty_settings = TtySettings::from_parser(ArgParser::from_args(&args));
+		assert!(tty_settings.enabled);
+		assert_eq!(
+			(100, 30, 1),
+			(tty_settings.cols, tty_settings.rows, tty_settings.scale)
+		);
+		assert_eq!(TtyTheme::parse("light"), tty_settings.theme);
+		let theme = TtyTheme::default();
+		assert_eq!(
+			rgb(255, 0, 0),
+			theme.get_color(CellColor::Indexed(196), theme.foreground)
+		);
+		assert_eq!(
+			rgb(8, 8, 8),
+			theme.get_color(CellColor::Indexed(232), theme.foreground)
+		);
+		assert_eq!(
+			(theme.background, theme.palette[9]),
+			theme.get_colors(CellStyle {
+				fg: CellColor::Indexed(1),
+				bold: true,
+				inverse: true,
+				..CellStyle::default()
+			})
+		);
+		assert!(!TtySettings::from_parser(ArgParser::new(None)).enabled);
+		let palette = ["#000000", "#ff0000", "#00ff00", "#ffff00"];
+		assert_eq!(None, TtyTheme::from_colors("#ffffff", "#000000", &palette));
+		let theme = TtyTheme::from_colors(
+			"#ffffff",
+			"#101010",
+			&[palette, palette].concat(),
+		)
+		.unwrap_or_default();
+		assert_eq!(
+			(rgb(255, 255, 255), rgb(16, 16, 16), rgb(255, 0, 0)),
+			(theme.foreground, theme.background, theme.palette[9])
+		);
+	}
+	#[test]
+	fn test_cast_settings() {
+		let args = App::new("test")
+			.arg(Arg::with_name("cast").long("cast").takes_value(true))
+			.arg(
+				Arg::with_name("idle-limit")
+					.long("idle-limit")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("cast-speed")
+					.long("cast-speed")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("cast-theme")
+					.long("cast-theme")
+					.takes_value(true),
+			)
+			.get_matches_from(vec![
+				"test",
+				"--cast",
+				"demo.cast",
+				"--idle-limit",
+				"1.5",
+				"--cast-speed",
+				"2",
+				"--cast-theme",
+				"light",
+			]);
+		let cast_settings = CastSettings::from_parser(ArgParser::from_args(&args));
+		assert_eq!(Some(PathBuf::from("demo.cast")), cast_settings.file);
+		assert_eq!(Some(1.5), cast_settings.idle_limit);
+		assert_eq!(2., cast_settings.speed);
+		assert_eq!(2, cast_settings.scale);
+		assert_eq!(Some(TtyTheme::parse("light")), cast_settings.theme);
+		assert!(CastSettings::from_parser(ArgParser::new(None))
+			.file
+			.is_none());
+	}
+}
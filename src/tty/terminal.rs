@@ -0,0 +1,677 @@
+use crate::image::font::{BitmapFont, GLYPH_SIZE};
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use crate::tty::settings::TtyTheme;
+use std::mem;
+use std::str;
+
+/* Width of the tab stops */
+const TAB_WIDTH: usize = 8;
+
+/* Color of a terminal cell */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CellColor {
+	Default,
+	Indexed(u8),
+	Rgb(u8, u8, u8),
+}
+
+/* Style attributes of a terminal cell */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CellStyle {
+	pub fg: CellColor,
+	pub bg: CellColor,
+	pub bold: bool,
+	pub underline: bool,
+	pub inverse: bool,
+}
+
+/* Default initialization values for CellStyle */
+impl Default for CellStyle {
+	fn default() -> Self {
+		Self {
+			fg: CellColor::Default,
+			bg: CellColor::Default,
+			bold: false,
+			underline: false,
+			inverse: false,
+		}
+	}
+}
+
+/* Character and style of a terminal cell */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cell {
+	pub c: char,
+	pub style: CellStyle,
+}
+
+/* Default initialization values for Cell */
+impl Default for Cell {
+	fn default() -> Self {
+		Self {
+			c: ' ',
+			style: CellStyle::default(),
+		}
+	}
+}
+
+/* State of the escape sequence parser */
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ParserState {
+	Ground,
+	Escape,
+	Csi,
+	Osc,
+	OscEscape,
+	Charset,
+}
+
+/* Terminal emulator that interprets VT100/ANSI sequences into a cell grid */
+#[derive(Debug)]
+pub struct Terminal {
+	pub cols: usize,
+	pub rows: usize,
+	cells: Vec<Cell>,
+	alternate: Option<Vec<Cell>>,
+	cursor: (usize, usize),
+	saved_cursor: (usize, usize),
+	cursor_visible: bool,
+	wrap_pending: bool,
+	style: CellStyle,
+	scroll_region: (usize, usize),
+	state: ParserState,
+	params: String,
+	utf8: Vec<u8>,
+	response: Vec<u8>,
+}
+
+impl Terminal {
+	/**
+	 * Create a new Terminal object.
+	 *
+	 * @param  cols
+	 * @param  rows
+	 * @return Terminal
+	 */
+	pub fn new(cols: usize, rows: usize) -> Self {
+		let (cols, rows) = (cols.max(1), rows.max(1));
+		Self {
+			cols,
+			rows,
+			cells: vec![Cell::default(); cols * rows],
+			alternate: None,
+			cursor: (0, 0),
+			saved_cursor: (0, 0),
+			cursor_visible: true,
+			wrap_pending: false,
+			style: CellStyle::default(),
+			scroll_region: (0, rows - 1),
+			state: ParserState::Ground,
+			params: String::new(),
+			utf8: Vec::new(),
+			response: Vec::new(),
+		}
+	}
+
+	/**
+	 * Get the cell at the given column and row.
+	 *
+	 * @param  col
+	 * @param  row
+	 * @return Cell (Option)
+	 */
+	#[cfg(test)]
+	pub fn get_cell(&self, col: usize, row: usize) -> Option<&Cell> {
+		if col < self.cols {
+			self.cells.get(row * self.cols + col)
+		} else {
+			None
+		}
+	}
+
+	/**
+	 * Get the text of the given row without the trailing spaces.
+	 *
+	 * @param  row
+	 * @return String
+	 */
+	#[cfg(test)]
+	pub fn get_line(&self, row: usize) -> String {
+		self.cells[row * self.cols..(row + 1) * self.cols]
+			.iter()
+			.map(|cell| cell.c)
+			.collect::<String>()
+			.trim_end()
+			.to_string()
+	}
+
+	/**
+	 * Take the responses to write back to the terminal input.
+	 *
+	 * @return Vector of u8
+	 */
+	pub fn take_response(&mut self) -> Vec<u8> {
+		mem::take(&mut self.response)
+	}
+
+	/**
+	 * Process the given output of the terminal.
+	 *
+	 * @param  data
+	 */
+	pub fn feed(&mut self, data: &[u8]) {
+		for byte in data {
+			match self.state {
+				ParserState::Ground => self.process_ground(*byte),
+				ParserState::Escape => self.process_escape(*byte),
+				ParserState::Csi => match byte {
+					0x30..=0x3f => self.params.push(*byte as char),
+					0x40..=0x7e => {
+						self.state = ParserState::Ground;
+						self.execute_csi(*byte as char);
+					}
+					0x1b => self.state = ParserState::Escape,
+					0x20..=0x2f => {}
+					_ => self.execute_control(*byte),
+				},
+				ParserState::Osc => match byte {
+					0x07 => self.state = ParserState::Ground,
+					0x1b => self.state = ParserState::OscEscape,
+					_ => {}
+				},
+				ParserState::OscEscape | ParserState::Charset => {
+					self.state = ParserState::Ground
+				}
+			}
+		}
+	}
+
+	/**
+	 * Process a byte in the ground state.
+	 *
+	 * @param  byte
+	 */
+	fn process_ground(&mut self, byte: u8) {
+		if byte >= 0x80 || !self.utf8.is_empty() {
+			self.utf8.push(byte);
+			match str::from_utf8(&self.utf8) {
+				Ok(text) => {
+					let text = text.to_string();
+					self.utf8.clear();
+					text.chars().for_each(|c| self.print(c));
+				}
+				Err(e) if e.error_len().is_some() => {
+					self.utf8.clear();
+					self.print(char::REPLACEMENT_CHARACTER);
+				}
+				Err(_) => {}
+			}
+		} else if byte == 0x1b {
+			self.state = ParserState::Escape;
+		} else if byte < 0x20 || byte == 0x7f {
+			self.execute_control(byte);
+		} else {
+			self.print(byte as char);
+		}
+	}
+
+	/**
+	 * Process a byte after the escape character.
+	 *
+	 * @param  byte
+	 */
+	fn process_escape(&mut self, byte: u8) {
+		self.state = ParserState::Ground;
+		match byte {
+			b'[' => {
+				self.params.clear();
+				self.state = ParserState::Csi;
+			}
+			b']' => self.state = ParserState::Osc,
+			b'(' | b')' | b'*' | b'+' => self.state = ParserState::Charset,
+			b'7' => self.saved_cursor = self.cursor,
+			b'8' => self.set_cursor(self.saved_cursor.0, self.saved_cursor.1),
+			b'D' => self.linefeed(),
+			b'E' => {
+				self.cursor.0 = 0;
+				self.linefeed();
+			}
+			b'M' => self.reverse_index(),
+			b'c' => *self = Self::new(self.cols, self.rows),
+			_ => {}
+		}
+	}
+
+	/**
+	 * Execute a control character.
+	 *
+	 * @param  byte
+	 */
+	fn execute_control(&mut self, byte: u8) {
+		match byte {
+			b'\r' => self.set_cursor(0, self.cursor.1),
+			b'\n' | 0x0b | 0x0c => self.linefeed(),
+			0x08 => self.set_cursor(self.cursor.0.saturating_sub(1), self.cursor.1),
+			b'\t' => self.set_cursor(
+				((self.cursor.0 / TAB_WIDTH) + 1) * TAB_WIDTH,
+				self.cursor.1,
+			),
+			_ => {}
+		}
+	}
+
+	/**
+	 * Print a character at the cursor position.
+	 *
+	 * @param  c
+	 */
+	fn print(&mut self, c: char) {
+		if self.wrap_pending {
+			self.cursor.0 = 0;
+			self.linefeed();
+		}
+		let index = self.cursor.1 * self.cols + self.cursor.0;
+		self.cells[index] = Cell {
+			c,
+			style: self.style,
+		};
+		if self.cursor.0 + 1 == self.cols {
+			self.wrap_pending = true;
+		} else {
+			self.cursor.0 += 1;
+		}
+	}
+
+	/**
+	 * Move the cursor to the given position.
+	 *
+	 * @param  col
+	 * @param  row
+	 */
+	fn set_cursor(&mut self, col: usize, row: usize) {
+		self.cursor = (col.min(self.cols - 1), row.min(self.rows - 1));
+		self.wrap_pending = false;
+	}
+
+	/* Move the cursor to the next line and scroll if necessary. */
+	fn linefeed(&mut self) {
+		self.wrap_pending = false;
+		if self.cursor.1 == self.scroll_region.1 {
+			self.scroll_up(1);
+		} else if self.cursor.1 + 1 < self.rows {
+			self.cursor.1 += 1;
+		}
+	}
+
+	/* Move the cursor to the previous line and scroll if necessary. */
+	fn reverse_index(&mut self) {
+		self.wrap_pending = false;
+		if self.cursor.1 == self.scroll_region.0 {
+			self.scroll_down(1);
+		} else {
+			self.cursor.1 = self.cursor.1.saturating_sub(1);
+		}
+	}
+
+	/**
+	 * Get an empty cell with the current background color.
+	 *
+	 * @return Cell
+	 */
+	fn get_blank(&self) -> Cell {
+		Cell {
+			c: ' ',
+			style: CellStyle {
+				bg: self.style.bg,
+				..CellStyle::default()
+			},
+		}
+	}
+
+	/**
+	 * Clear the cells between the given indices.
+	 *
+	 * @param  start
+	 * @param  end
+	 */
+	fn clear(&mut self, start: usize, end: usize) {
+		let blank = self.get_blank();
+		let end = end.min(self.cells.len());
+		if start < end {
+			self.cells[start..end]
+				.iter_mut()
+				.for_each(|cell| *cell = blank);
+		}
+	}
+
+	/**
+	 * Scroll the lines of the scroll region up.
+	 *
+	 * @param  count
+	 */
+	fn scroll_up(&mut self, count: usize) {
+		let (top, bottom) = self.scroll_region;
+		self.delete_lines_at(top, bottom, count);
+	}
+
+	/**
+	 * Scroll the lines of the scroll region down.
+	 *
+	 * @param  count
+	 */
+	fn scroll_down(&mut self, count: usize) {
+		let (top, bottom) = self.scroll_region;
+		self.insert_lines_at(top, bottom, count);
+	}
+
+	/**
+	 * Delete lines at the given row and shift the lines below up.
+	 *
+	 * @param  row
+	 * @param  bottom
+	 * @param  count
+	 */
+	fn delete_lines_at(&mut self, row: usize, bottom: usize, count: usize) {
+		let count = count.min(bottom + 1 - row);
+		let (start, end) = (row * self.cols, (bottom + 1) * self.cols);
+		self.cells[start..end].rotate_left(count * self.cols);
+		self.clear(end - count * self.cols, end);
+	}
+
+	/**
+	 * Insert lines at the given row and shift the lines below down.
+	 *
+	 * @param  row
+	 * @param  bottom
+	 * @param  count
+	 */
+	fn insert_lines_at(&mut self, row: usize, bottom: usize, count: usize) {
+		let count = count.min(bottom + 1 - row);
+		let (start, end) = (row * self.cols, (bottom + 1) * self.cols);
+		self.cells[start..end].rotate_right(count * self.cols);
+		self.clear(start, start + count * self.cols);
+	}
+
+	/**
+	 * Switch between the main and alternate screens.
+	 *
+	 * @param  enabled
+	 */
+	fn set_alternate_screen(&mut self, enabled: bool) {
+		if enabled && self.alternate.is_none() {
+			self.alternate = Some(self.cells.clone());
+			self.clear(0, self.cells.len());
+		} else if !enabled {
+			if let Some(cells) = self.alternate.take() {
+				self.cells = cells;
+			}
+		}
+	}
+
+	/**
+	 * Execute a control sequence with the given final character.
+	 *
+	 * @param  action
+	 */
+	fn execute_csi(&mut self, action: char) {
+		let private = self.params.starts_with('?');
+		let params = self
+			.params
+			.trim_start_matches(['?', '>', '='])
+			.split(';')
+			.map(|v| {
+				v.bytes().filter(u8::is_ascii_digit).fold(
+					0,
+					|value: usize, digit| {
+						value
+							.saturating_mul(10)
+							.saturating_add((digit - b'0').into())
+					},
+				)
+			})
+			.collect::<Vec<usize>>();
+		let arg = |i: usize, default: usize| match params.get(i) {
+			Some(value) if *value > 0 => *value,
+			_ => default,
+		};
+		let (col, row) = self.cursor;
+		let index = row * self.cols + col;
+		match action {
+			'A' => self.set_cursor(col, row.saturating_sub(arg(0, 1))),
+			'B' | 'e' => self.set_cursor(col, row.saturating_add(arg(0, 1))),
+			'C' | 'a' => self.set_cursor(col.saturating_add(arg(0, 1)), row),
+			'D' => self.set_cursor(col.saturating_sub(arg(0, 1)), row),
+			'E' => self.set_cursor(0, row.saturating_add(arg(0, 1))),
+			'F' => self.set_cursor(0, row.saturating_sub(arg(0, 1))),
+			'G' | '`' => self.set_cursor(arg(0, 1) - 1, row),
+			'd' => self.set_cursor(col, arg(0, 1) - 1),
+			'H' | 'f' => self.set_cursor(arg(1, 1) - 1, arg(0, 1) - 1),
+			'J' => match arg(0, 0) {
+				0 => self.clear(index, self.cells.len()),
+				1 => self.clear(0, index + 1),
+				_ => self.clear(0, self.cells.len()),
+			},
+			'K' => match arg(0, 0) {
+				0 => self.clear(index, (row + 1) * self.cols),
+				1 => self.clear(row * self.cols, index + 1),
+				_ => self.clear(row * self.cols, (row + 1) * self.cols),
+			},
+			'L' if row >= self.scroll_region.0 && row <= self.scroll_region.1 => {
+				self.insert_lines_at(row, self.scroll_region.1, arg(0, 1))
+			}
+			'M' if row >= self.scroll_region.0 && row <= self.scroll_region.1 => {
+				self.delete_lines_at(row, self.scroll_region.1, arg(0, 1))
+			}
+			'@' => {
+				let count = arg(0, 1).min(self.cols - col);
+				let end = (row + 1) * self.cols;
+				self.cells[index..end].rotate_right(count);
+				self.clear(index, index + count);
+			}
+			'P' => {
+				let count = arg(0, 1).min(self.cols - col);
+				let end = (row + 1) * self.cols;
+				self.cells[index..end].rotate_left(count);
+				self.clear(end - count, end);
+			}
+			'X' => self.clear(index, index + arg(0, 1).min(self.cols - col)),
+			'S' => self.scroll_up(arg(0, 1)),
+			'T' => self.scroll_down(arg(0, 1)),
+			'm' => self.set_style(&params),
+			'r' => {
+				let (top, bottom) = (arg(0, 1) - 1, arg(1, self.rows) - 1);
+				if top < bottom && bottom < self.rows {
+					self.scroll_region = (top, bottom);
+					self.set_cursor(0, 0);
+				}
+			}
+			's' => self.saved_cursor = self.cursor,
+			'u' => self.set_cursor(self.saved_cursor.0, self.saved_cursor.1),
+			'n' => match arg(0, 0) {
+				5 => self.response.extend(b"\x1b[0n"),
+				6 => self
+					.response
+					.extend(format!("\x1b[{};{}R", row + 1, col + 1).as_bytes()),
+				_ => {}
+			},
+			'c' if !private => self.response.extend(b"\x1b[?1;2c"),
+			'h' | 'l' if private => {
+				for mode in &params {
+					match mode {
+						25 => self.cursor_visible = action == 'h',
+						47 | 1047 | 1049 => self.set_alternate_screen(action == 'h'),
+						_ => {}
+					}
+				}
+			}
+			_ => {}
+		}
+	}
+
+	/**
+	 * Set the style attributes with the given SGR parameters.
+	 *
+	 * @param  params
+	 */
+	fn set_style(&mut self, params: &[usize]) {
+		let mut params = params.iter().copied();
+		while let Some(param) = params.next() {
+			match param {
+				0 => self.style = CellStyle::default(),
+				1 => self.style.bold = true,
+				4 => self.style.underline = true,
+				7 => self.style.inverse = true,
+				22 => self.style.bold = false,
+				24 => self.style.underline = false,
+				27 => self.style.inverse = false,
+				30..=37 => self.style.fg = CellColor::Indexed(param as u8 - 30),
+				38 => self.style.fg = Self::parse_color(&mut params),
+				39 => self.style.fg = CellColor::Default,
+				40..=47 => self.style.bg = CellColor::Indexed(param as u8 - 40),
+				48 => self.style.bg = Self::parse_color(&mut params),
+				49 => self.style.bg = CellColor::Default,
+				90..=97 => self.style.fg = CellColor::Indexed(param as u8 - 82),
+				100..=107 => self.style.bg = CellColor::Indexed(param as u8 - 92),
+				_ => {}
+			}
+		}
+	}
+
+	/**
+	 * Parse an extended color from the SGR parameters.
+	 *
+	 * @param  params
+	 * @return CellColor
+	 */
+	fn parse_color<I: Iterator<Item = usize>>(params: &mut I) -> CellColor {
+		match params.next() {
+			Some(5) => CellColor::Indexed(params.next().unwrap_or_default() as u8),
+			Some(2) => CellColor::Rgb(
+				params.next().unwrap_or_default() as u8,
+				params.next().unwrap_or_default() as u8,
+				params.next().unwrap_or_default() as u8,
+			),
+			_ => CellColor::Default,
+		}
+	}
+
+	/**
+	 * Render the cells as an image.
+	 *
+	 * @param  font
+	 * @param  theme
+	 * @return Image
+	 */
+	pub fn render(&self, font: &BitmapFont, theme: &TtyTheme) -> Image {
+		let (cell_width, cell_height) = font.get_text_size(" ");
+		let padding = cell_width;
+		let geometry = Geometry::new(
+			0,
+			0,
+			self.cols as u32 * cell_width + padding * 2,
+			self.rows as u32 * cell_height + padding * 2,
+		);
+		let mut image = Image::new(
+			vec![theme.background; (geometry.width * geometry.height) as usize],
+			false,
+			geometry,
+		);
+		for (i, cell) in self.cells.iter().enumerate() {
+			let (x, y) = (
+				(padding + (i % self.cols) as u32 * cell_width) as i32,
+				(padding + (i / self.cols) as u32 * cell_height) as i32,
+			);
+			let (fg, bg) = theme.get_colors(cell.style);
+			if bg != theme.background {
+				image.fill_rect(
+					Geometry::new(x, y, cell_width, cell_height),
+					bg,
+					1.,
+				);
+			}
+			if cell.c != ' ' {
+				font.draw(&mut image, &Self::get_printable(cell.c), (x, y), fg, 1.);
+			}
+			if cell.style.underline {
+				image.fill_rect(
+					Geometry::new(
+						x,
+						y + (cell_height - cell_height / GLYPH_SIZE.1) as i32,
+						cell_width,
+						cell_height / GLYPH_SIZE.1,
+					),
+					fg,
+					1.,
+				);
+			}
+		}
+		if self.cursor_visible && self.alternate.is_none() {
+			image.fill_rect(
+				Geometry::new(
+					(padding + self.cursor.0 as u32 * cell_width) as i32,
+					(padding + self.cursor.1 as u32 * cell_height) as i32,
+					cell_width,
+					cell_height,
+				),
+				theme.foreground,
+				0.5,
+			);
+		}
+		image
+	}
+
+	/**
+	 * Get the printable representation of the given character.
+	 *
+	 * @param  c
+	 * @return String
+	 */
+	fn get_printable(c: char) -> String {
+		match c {
+			'─' | '━' | '═' => '-',
+			'│' | '┃' | '║' => '|',
+			'┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' | '╭' | '╮'
+			| '╯' | '╰' => '+',
+			'█' | '▓' | '▒' | '░' => '#',
+			'•' | '·' => '*',
+			'‘' | '’' => '\'',
+			'“' | '”' => '"',
+			_ => c,
+		}
+		.to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_terminal() {
+		let mut terminal = Terminal::new(10, 3);
+		terminal.feed(b"hello\r\nworld\x1b[1;31m!\x1b[0m");
+		assert_eq!("hello", terminal.get_line(0));
+		assert_eq!("world!", terminal.get_line(1));
+		let cell = terminal.get_cell(5, 1).unwrap();
+		assert!(cell.style.bold);
+		assert_eq!(CellColor::Indexed(1), cell.style.fg);
+		terminal.feed(b"\x1b[2;3H\x1b[K\x1b[1;1HH\xc3\xa9");
+		assert_eq!("Hé", terminal.get_line(0).get(..3).unwrap());
+		assert_eq!("wo", terminal.get_line(1));
+		terminal.feed(b"\x1b[3;1Habcdefghijkl");
+		assert_eq!("wo", terminal.get_line(0));
+		assert_eq!("abcdefghij", terminal.get_line(1));
+		assert_eq!("kl", terminal.get_line(2));
+		terminal.feed(b"\x1b]0;title\x07\x1b[6n\x1b[2J");
+		assert_eq!(b"\x1b[3;3R".to_vec(), terminal.take_response());
+		assert!((0..3).all(|row| terminal.get_line(row).is_empty()));
+		terminal.feed(b"\x1b[?1049hvim\x1b[?1049l");
+		assert_eq!("", terminal.get_line(0));
+		let image = terminal.render(&BitmapFont::new(1), &TtyTheme::default());
+		assert_eq!(Geometry::new(0, 0, 72, 42), image.geometry);
+		terminal
+			.feed(b"\x1b[99999999999999999999999C\x1b[99999999999999999999999Bx");
+		assert_eq!("         x", terminal.get_line(2));
+	}
+}
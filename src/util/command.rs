@@ -1,5 +1,5 @@
 use std::io::Error;
-use std::process::Command as OsCommand;
+use std::process::{Child, Command as OsCommand, Stdio};
 
 /* The command and its arguments */
 #[derive(Debug)]
@@ -46,6 +46,19 @@ impl<'a> Command<'a> {
 			Err(e) => Err(e),
 		}
 	}
+
+	/**
+	 * Spawn the command with piped standard input and error.
+	 *
+	 * @return Child (Result)
+	 */
+	pub fn spawn_piped(&self) -> Result<Child, Error> {
+		OsCommand::new(self.cmd)
+			.args(&self.args)
+			.stdin(Stdio::piped())
+			.stderr(Stdio::piped())
+			.spawn()
+	}
 }
 
 #[cfg(test)]
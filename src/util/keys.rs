@@ -3,10 +3,12 @@ use std::fmt;
 use std::str::FromStr;
 
 /* Types of key bindings. */
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
 pub enum KeyType {
 	ActionKeys,
 	CancelKeys,
+	MarkerKeys,
 }
 
 /* Operational keys and combinations */
@@ -18,6 +20,9 @@ pub struct ActionKeys {
 /* Alias for cancel keys */
 pub type CancelKeys = ActionKeys;
 
+/* Alias for marker keys */
+pub type MarkerKeys = ActionKeys;
+
 /* Display implementation for user-facing output */
 impl fmt::Display for ActionKeys {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -68,6 +73,9 @@ impl ActionKeys {
 					vec![Keycode::Escape],
 				],
 			},
+			KeyType::MarkerKeys => Self {
+				key_groups: vec![vec![Keycode::LAlt, Keycode::M]],
+			},
 		}
 	}
 
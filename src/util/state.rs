@@ -1,4 +1,4 @@
-use crate::util::keys::{ActionKeys, CancelKeys, KeyType};
+use crate::util::keys::{ActionKeys, CancelKeys, KeyType, MarkerKeys};
 use device_query::{DeviceQuery, DeviceState};
 use std::fmt;
 
@@ -7,6 +7,7 @@ pub struct InputState {
 	pub state: DeviceState,
 	pub action_keys: ActionKeys,
 	pub cancel_keys: CancelKeys,
+	pub marker_keys: Option<MarkerKeys>,
 	check_mouse: bool,
 }
 
@@ -16,6 +17,7 @@ impl Default for InputState {
 		Self::new(
 			ActionKeys::default(KeyType::ActionKeys),
 			CancelKeys::default(KeyType::CancelKeys),
+			None,
 			false,
 		)
 	}
@@ -40,18 +42,21 @@ impl InputState {
 	 *
 	 * @param  action_keys
 	 * @param  cancel_keys
+	 * @param  marker_keys (Option)
 	 * @param  check_mouse
 	 * @return InputState
 	 */
 	pub fn new(
 		action_keys: ActionKeys,
 		cancel_keys: CancelKeys,
+		marker_keys: Option<MarkerKeys>,
 		check_mouse: bool,
 	) -> Self {
 		Self {
 			state: DeviceState::new(),
 			action_keys,
 			cancel_keys,
+			marker_keys,
 			check_mouse,
 		}
 	}
@@ -79,6 +84,22 @@ impl InputState {
 		}
 	}
 
+	/**
+	 * Check for action keys and mouse clicks regardless of the mouse setting.
+	 *
+	 * @return bool
+	 */
+	pub fn check_step(&self) -> bool {
+		self.action_keys.check(self.state.get_keys())
+			|| self
+				.state
+				.get_mouse()
+				.button_pressed
+				.get(1)
+				.copied()
+				.unwrap_or_default()
+	}
+
 	/**
 	 * Check if the cancel keys are pressed.
 	 *
@@ -87,6 +108,18 @@ impl InputState {
 	pub fn check_cancel_keys(&self) -> bool {
 		self.cancel_keys.check(self.state.get_keys())
 	}
+
+	/**
+	 * Check if the marker keys are pressed.
+	 *
+	 * @return bool
+	 */
+	pub fn check_marker_keys(&self) -> bool {
+		match &self.marker_keys {
+			Some(keys) => keys.check(self.state.get_keys()),
+			None => false,
+		}
+	}
 }
 
 #[cfg(test)]
@@ -98,6 +131,8 @@ mod tests {
 		let input_state = InputState::default().into_boxed_state();
 		assert!(!input_state.check_action());
 		assert!(!input_state.check_cancel_keys());
+		assert!(!input_state.check_step());
+		assert!(!input_state.check_marker_keys());
 		assert!(format!("{:?}", input_state).len() > 0);
 	}
 }
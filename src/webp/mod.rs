@@ -0,0 +1,344 @@
+pub mod settings;
+
+use crate::anim::settings::AnimSettings;
+use crate::app::{AppError, AppResult};
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use crate::util::state::InputState;
+use image::error::{DecodingError, ImageFormatHint};
+use image::{
+	Delay, DynamicImage, ExtendedColorType, Frame, ImageError, ImageFormat,
+	ImageResult, RgbImage, RgbaImage,
+};
+use libwebp_sys::*;
+use std::convert::TryInto;
+use std::ffi::CStr;
+use std::io::{self, Read, Write};
+use std::mem::MaybeUninit;
+use std::{ptr, slice};
+use webp::{AnimDecoder, PixelLayout};
+
+/* Animated WebP encoder and settings */
+pub struct WebPEncoder<'a, Output: Write> {
+	fps: u32,
+	geometry: Geometry,
+	output: Output,
+	config: WebPConfig,
+	settings: &'a AnimSettings,
+}
+
+impl<'a, Output: Write> WebPEncoder<'a, Output> {
+	/**
+	 * Create a new WebPEncoder object.
+	 *
+	 * @param  fps
+	 * @param  geometry
+	 * @param  output
+	 * @param  settings
+	 * @return WebPEncoder (Result)
+	 */
+	pub fn new(
+		fps: u32,
+		geometry: Geometry,
+		output: Output,
+		settings: &'a AnimSettings,
+	) -> AppResult<Self> {
+		let mut config = WebPConfig::new().map_err(|_| {
+			AppError::WebPEncoding(String::from("Failed to create the config"))
+		})?;
+		config.lossless = settings.webp.lossless.into();
+		config.quality = settings.quality.into();
+		Ok(Self {
+			fps,
+			geometry,
+			output,
+			config,
+			settings,
+		})
+	}
+
+	/**
+	 * Encode images as frame and write to the WebP file.
+	 *
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	pub fn save(
+		mut self,
+		images: Vec<Image>,
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		let mut encoder = AnimEncoder::new(self.geometry, self.settings.repeat)?;
+		let mut timestamp = 0;
+		for (i, image) in images.iter().enumerate() {
+			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
+			info!("Saving... ({:.1}%)\r", percentage);
+			debug!(
+				"Encoding... ({:.1}%) [{}/{}]\r",
+				percentage,
+				i + 1,
+				images.len()
+			);
+			io::stdout().flush()?;
+			if let Some(state) = input_state {
+				if state.check_cancel_keys() {
+					info!("\n");
+					warn!("User interrupt detected.");
+					panic!("Failed to write the frames")
+				}
+			}
+			encoder.add(image, timestamp, &self.config)?;
+			timestamp += image
+				.delay
+				.unwrap_or(1000 / self.fps.max(1))
+				.try_into()
+				.unwrap_or(i32::MAX);
+		}
+		self.output.write_all(&encoder.assemble(timestamp)?)?;
+		info!("\n");
+		Ok(())
+	}
+}
+
+/* Wrapper for the animation encoder of libwebp
+ *
+ * webp::AnimEncoder finishes the animation with a zero timestamp that
+ * libwebp rejects as decreasing, so the last frame gets the average duration
+ * of the previous frames instead of its own delay. The encoder is used
+ * directly here to pass the end timestamp of the last frame. */
+struct AnimEncoder {
+	encoder: *mut WebPAnimEncoder,
+}
+
+impl AnimEncoder {
+	/**
+	 * Create a new AnimEncoder object.
+	 *
+	 * @param  geometry
+	 * @param  repeat
+	 * @return AnimEncoder (Result)
+	 */
+	fn new(geometry: Geometry, repeat: i32) -> AppResult<Self> {
+		let encoder = unsafe {
+			let mut options = MaybeUninit::<WebPAnimEncoderOptions>::uninit();
+			if WebPAnimEncoderOptionsInitInternal(
+				options.as_mut_ptr(),
+				WebPGetMuxABIVersion(),
+			) == 0
+			{
+				return Err(AppError::WebPEncoding(String::from(
+					"Failed to create the encoder options",
+				)));
+			}
+			let mut options = options.assume_init();
+			options.anim_params.loop_count =
+				if repeat >= 0 { repeat + 1 } else { 0 };
+			WebPAnimEncoderNewInternal(
+				geometry.width.try_into().unwrap_or_default(),
+				geometry.height.try_into().unwrap_or_default(),
+				&options,
+				WebPGetMuxABIVersion(),
+			)
+		};
+		if encoder.is_null() {
+			Err(AppError::WebPEncoding(String::from(
+				"Failed to create the encoder",
+			)))
+		} else {
+			Ok(Self { encoder })
+		}
+	}
+
+	/**
+	 * Add an image as frame that starts at the given timestamp.
+	 *
+	 * @param  image
+	 * @param  timestamp
+	 * @param  config
+	 * @return Result
+	 */
+	fn add(
+		&mut self,
+		image: &Image,
+		timestamp: i32,
+		config: &WebPConfig,
+	) -> AppResult<()> {
+		let data = image.get_data(ExtendedColorType::Rgba8);
+		let mut picture = WebPPicture::new().map_err(|_| {
+			AppError::WebPEncoding(String::from("Failed to create the picture"))
+		})?;
+		picture.use_argb = 1;
+		picture.width = image.geometry.width.try_into().unwrap_or_default();
+		picture.height = image.geometry.height.try_into().unwrap_or_default();
+		let added = unsafe {
+			let added = WebPPictureImportRGBA(
+				&mut picture,
+				data.as_ptr(),
+				picture.width * 4,
+			) != 0 && WebPAnimEncoderAdd(
+				self.encoder,
+				&mut picture,
+				timestamp,
+				config,
+			) != 0;
+			WebPPictureFree(&mut picture);
+			added
+		};
+		if added {
+			Ok(())
+		} else {
+			Err(self.get_error())
+		}
+	}
+
+	/**
+	 * Assemble the frames into an animation that ends at the given timestamp.
+	 *
+	 * @param  timestamp
+	 * @return Vector of u8 (Result)
+	 */
+	fn assemble(self, timestamp: i32) -> AppResult<Vec<u8>> {
+		let mut data = WebPData::default();
+		unsafe {
+			if WebPAnimEncoderAdd(
+				self.encoder,
+				ptr::null_mut(),
+				timestamp,
+				ptr::null(),
+			) == 0 || WebPAnimEncoderAssemble(self.encoder, &mut data) == 0
+			{
+				return Err(self.get_error());
+			}
+			let bytes = slice::from_raw_parts(data.bytes, data.size).to_vec();
+			WebPDataClear(&mut data);
+			Ok(bytes)
+		}
+	}
+
+	/**
+	 * Get the last error of the encoder.
+	 *
+	 * @return AppError
+	 */
+	fn get_error(&self) -> AppError {
+		AppError::WebPEncoding(
+			unsafe { CStr::from_ptr(WebPAnimEncoderGetError(self.encoder)) }
+				.to_string_lossy()
+				.into_owned(),
+		)
+	}
+}
+
+/* Drop implementation for releasing the encoder */
+impl Drop for AnimEncoder {
+	fn drop(&mut self) {
+		unsafe { WebPAnimEncoderDelete(self.encoder) }
+	}
+}
+
+/* Animated WebP decoder */
+pub struct WebPDecoder<Input: Read> {
+	input: Input,
+}
+
+impl<Input: Read> WebPDecoder<Input> {
+	/**
+	 * Create a new WebPDecoder object.
+	 *
+	 * @param  input
+	 * @return WebPDecoder
+	 */
+	pub fn new(input: Input) -> Self {
+		Self { input }
+	}
+
+	/**
+	 * Decode the animation into frames.
+	 *
+	 * @return Vector of Frame (ImageResult)
+	 */
+	pub fn into_frames(mut self) -> ImageResult<Vec<Frame>> {
+		let get_error = |message: String| {
+			ImageError::Decoding(DecodingError::new(
+				ImageFormatHint::Exact(ImageFormat::WebP),
+				message,
+			))
+		};
+		let mut data = Vec::new();
+		self.input.read_to_end(&mut data)?;
+		let animation = AnimDecoder::new(&data).decode().map_err(get_error)?;
+		let mut previous = 0;
+		(0..animation.len())
+			.filter_map(|i| animation.get_frame(i))
+			.map(|frame| {
+				let (width, height) = (frame.width(), frame.height());
+				let data = frame.get_image().to_vec();
+				let image = match frame.get_layout() {
+					PixelLayout::Rgba => RgbaImage::from_raw(width, height, data),
+					PixelLayout::Rgb => RgbImage::from_raw(width, height, data)
+						.map(|image| DynamicImage::ImageRgb8(image).into_rgba8()),
+				}
+				.ok_or_else(|| get_error(String::from("Invalid frame data")))?;
+				let delay = (frame.get_time_ms() - previous).max(0);
+				previous = frame.get_time_ms();
+				Ok(Frame::from_parts(
+					image,
+					0,
+					0,
+					Delay::from_numer_denom_ms(
+						delay.try_into().unwrap_or_default(),
+						1,
+					),
+				))
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::Bgra;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_webp_encoder() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 2, 1);
+		let mut images = vec![
+			Image::new(
+				vec![Bgra::from([0, 0, 255, 255]), Bgra::from([0, 0, 0, 0])],
+				true,
+				geometry,
+			),
+			Image::new(
+				vec![Bgra::from([255, 0, 0, 255]), Bgra::from([0, 255, 0, 255])],
+				true,
+				geometry,
+			),
+		];
+		images[0].delay = Some(200);
+		let mut settings = AnimSettings::default();
+		settings.webp.lossless = true;
+		let mut output = Vec::new();
+		WebPEncoder::new(10, geometry, &mut output, &settings)?
+			.save(images, None)?;
+		let frames = WebPDecoder::new(output.as_slice()).into_frames()?;
+		assert_eq!(2, frames.len());
+		assert_eq!(
+			vec![(200, 1), (100, 1)],
+			frames
+				.iter()
+				.map(|frame| frame.delay().numer_denom_ms())
+				.collect::<Vec<(u32, u32)>>()
+		);
+		assert_eq!(
+			vec![255, 0, 0, 255, 0, 0, 0, 0],
+			frames[0].buffer().clone().into_raw()
+		);
+		assert_eq!(
+			vec![0, 0, 255, 255, 0, 255, 0, 255],
+			frames[1].buffer().clone().into_raw()
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,17 @@
+/* WebP encoding settings */
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WebPSettings {
+	pub lossless: bool,
+}
+
+impl WebPSettings {
+	/**
+	 * Create a new WebPSettings object.
+	 *
+	 * @param  lossless
+	 * @return WebPSettings
+	 */
+	pub fn new(lossless: bool) -> Self {
+		Self { lossless }
+	}
+}
@@ -10,11 +10,13 @@ pub trait Access<'a, Window: Capture + Send + Sync + Copy + Debug + 'static> {
 	where
 		Self: Sized;
 	fn get_window(&mut self) -> Option<Window>;
+	fn get_windows(&mut self, targets: &[String]) -> Vec<Window>;
 }
 
 /* Window methods for capturing an image */
 pub trait Capture {
 	fn get_image(&self) -> Option<Image>;
+	fn get_position(&self) -> (i32, i32);
 	fn show_countdown(&self);
 	fn release(&self);
 }
@@ -43,6 +43,15 @@ impl Capture for TestWindow {
 		))
 	}
 
+	/**
+	 * Get the position of the test window.
+	 *
+	 * @return Tuple of x and y
+	 */
+	fn get_position(&self) -> (i32, i32) {
+		(self.geometry.x, self.geometry.y)
+	}
+
 	/* Do not show countdown for testing window. */
 	fn show_countdown(&self) {}
 
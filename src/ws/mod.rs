@@ -26,4 +26,13 @@ impl<'a> Access<'a, Window> for WindowSystem {
 	fn get_window(&mut self) -> Option<Window> {
 		unimplemented!()
 	}
+	/**
+	 * Get the windows to record with the given IDs or names.
+	 *
+	 * @param  targets
+	 * @return Vector of Window
+	 */
+	fn get_windows(&mut self, _targets: &[String]) -> Vec<Window> {
+		unimplemented!()
+	}
 }
@@ -28,6 +28,15 @@ impl Capture for Window {
 		unimplemented!()
 	}
 
+	/**
+	 * Get the position of the window on the screen.
+	 *
+	 * @return Tuple of x and y
+	 */
+	fn get_position(&self) -> (i32, i32) {
+		unimplemented!()
+	}
+
 	/* Show countdown on the window. */
 	fn show_countdown(&self) {
 		unimplemented!()
@@ -5,11 +5,12 @@ use crate::util::state::InputState;
 use crate::x11::window::Window;
 use device_query::{DeviceQuery, Keycode};
 use std::convert::TryInto;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::io::{self, Write};
 use std::mem::MaybeUninit;
-use std::os::raw::{c_int, c_ulong};
+use std::os::raw::{c_char, c_int, c_uchar, c_uint, c_ulong, c_void};
 use std::ptr;
+use std::slice;
 use std::thread;
 use std::time::{Duration, Instant};
 use x11::xlib;
@@ -131,6 +132,209 @@ impl Display {
 		};
 	}
 
+	/**
+	 * Get the top-level window that contains the focused window.
+	 *
+	 * @return xid (Option)
+	 */
+	unsafe fn get_focused_top_level(&self) -> Option<c_ulong> {
+		let root = xlib::XDefaultRootWindow(self.inner);
+		let (mut xid, mut focus_state) = (0, 0);
+		xlib::XGetInputFocus(self.inner, &mut xid, &mut focus_state);
+		while xid != 0 && xid != xlib::PointerRoot as c_ulong && xid != root {
+			let (mut root_xid, mut parent) = (0, 0);
+			let mut children: *mut c_ulong = ptr::null_mut();
+			let mut nchildren: c_uint = 0;
+			if xlib::XQueryTree(
+				self.inner,
+				xid,
+				&mut root_xid,
+				&mut parent,
+				&mut children,
+				&mut nchildren,
+			) == 0
+			{
+				return None;
+			}
+			if !children.is_null() {
+				xlib::XFree(children as *mut c_void);
+			}
+			if parent == root {
+				return Some(xid);
+			}
+			xid = parent;
+		}
+		None
+	}
+
+	/**
+	 * Get the title of the window from _NET_WM_NAME or WM_NAME.
+	 *
+	 * @param  xid
+	 * @return String (Option)
+	 */
+	unsafe fn get_window_title(&self, xid: c_ulong) -> Option<String> {
+		let net_wm_name = self.get_atom("_NET_WM_NAME");
+		let utf8_string = self.get_atom("UTF8_STRING");
+		let (mut actual_type, mut actual_format) = (0, 0);
+		let (mut nitems, mut bytes_after) = (0, 0);
+		let mut data: *mut c_uchar = ptr::null_mut();
+		if xlib::XGetWindowProperty(
+			self.inner,
+			xid,
+			net_wm_name,
+			0,
+			1024,
+			xlib::False,
+			utf8_string,
+			&mut actual_type,
+			&mut actual_format,
+			&mut nitems,
+			&mut bytes_after,
+			&mut data,
+		) == xlib::Success as c_int
+			&& !data.is_null()
+		{
+			let title = if actual_type == utf8_string && actual_format == 8 {
+				Some(
+					String::from_utf8_lossy(slice::from_raw_parts(
+						data,
+						nitems as usize,
+					))
+					.into_owned(),
+				)
+			} else {
+				None
+			};
+			xlib::XFree(data as *mut c_void);
+			if title.is_some() {
+				return title;
+			}
+		}
+		let mut window_name: *mut c_char = ptr::null_mut();
+		if xlib::XFetchName(self.inner, xid, &mut window_name) != 0
+			&& !window_name.is_null()
+		{
+			let title = CStr::from_ptr(window_name).to_string_lossy().into_owned();
+			xlib::XFree(window_name as *mut c_void);
+			Some(title)
+		} else {
+			None
+		}
+	}
+
+	/**
+	 * Get the atom with the given name.
+	 *
+	 * @param  name
+	 * @return Atom
+	 */
+	unsafe fn get_atom(&self, name: &str) -> xlib::Atom {
+		let name = CString::new(name).expect("Failed to create CString");
+		xlib::XInternAtom(self.inner, name.as_ptr(), xlib::False)
+	}
+
+	/**
+	 * Find a viewable window that has the given name in its title.
+	 *
+	 * @param  xid
+	 * @param  name
+	 * @param  ignored (Option)
+	 * @return xid (Option)
+	 */
+	unsafe fn find_window(
+		&self,
+		xid: c_ulong,
+		name: &str,
+		ignored: Option<c_ulong>,
+	) -> Option<c_ulong> {
+		let mut attributes = MaybeUninit::<xlib::XWindowAttributes>::uninit();
+		if ignored == Some(xid)
+			|| xlib::XGetWindowAttributes(self.inner, xid, attributes.as_mut_ptr())
+				== 0 || attributes.assume_init().map_state != xlib::IsViewable
+		{
+			return None;
+		}
+		if matches!(self.get_window_title(xid), Some(title) if title.contains(name))
+		{
+			return Some(xid);
+		}
+		let (mut root, mut parent) = (0, 0);
+		let mut children: *mut c_ulong = ptr::null_mut();
+		let mut nchildren: c_uint = 0;
+		if xlib::XQueryTree(
+			self.inner,
+			xid,
+			&mut root,
+			&mut parent,
+			&mut children,
+			&mut nchildren,
+		) == 0 || children.is_null()
+		{
+			return None;
+		}
+		let found = slice::from_raw_parts(children, nchildren as usize)
+			.iter()
+			.rev()
+			.find_map(|child| self.find_window(*child, name, ignored));
+		xlib::XFree(children as *mut c_void);
+		found
+	}
+
+	/**
+	 * Get the window with the given ID or name.
+	 *
+	 * @param  target (e.g. 0x1e00004, xterm)
+	 * @return Window (Option)
+	 */
+	pub fn get_window_by_target(&self, target: &str) -> Option<Window> {
+		let xid = match target.strip_prefix("0x") {
+			Some(hex) => c_ulong::from_str_radix(hex, 16).ok(),
+			None => target.parse().ok(),
+		};
+		let xid = match xid {
+			Some(xid) => Some(xid),
+			None => unsafe {
+				self.find_window(xlib::XDefaultRootWindow(self.inner), target, None)
+			},
+		}?;
+		Some(Window::new(xid, *self))
+	}
+
+	/**
+	 * Wait for a window that has the given name in its title.
+	 * The window that is focused at the start (e.g. the terminal) is ignored.
+	 *
+	 * @param  name
+	 * @param  input_state (Option)
+	 * @return Window (Option)
+	 */
+	pub fn wait_for_window(
+		&self,
+		name: &str,
+		input_state: Option<&InputState>,
+	) -> Option<Window> {
+		info!("Waiting for a window that matches {:?}...", name);
+		let start_time = Instant::now();
+		let focused = unsafe { self.get_focused_top_level() };
+		loop {
+			let root = unsafe { xlib::XDefaultRootWindow(self.inner) };
+			if let Some(xid) = unsafe { self.find_window(root, name, focused) } {
+				let window = Window::new(xid, *self);
+				info!("{}", window);
+				return Some(window);
+			} else if matches!(input_state, Some(state) if state.check_cancel_keys())
+			{
+				warn!("User interrupt detected.");
+				return None;
+			} else if start_time.elapsed().as_secs() > self.settings.time.timeout {
+				warn!("The operation timed out.");
+				return None;
+			}
+			thread::sleep(Duration::from_millis(self.settings.time.interval));
+		}
+	}
+
 	/**
 	 * Get the type of Window given with RecordWindow enum.
 	 *
@@ -381,7 +585,7 @@ mod tests {
 	#[test]
 	fn test_x11_display() {
 		let mut settings = RecordSettings::default();
-		settings.time = RecordTime::new(Some(0.0), 0, 0, 10);
+		settings.time = RecordTime::new(Some(0.0), 0, 0, 10, None);
 		settings.flag.font = Some(DEFAULT_FONT);
 		let mut display = Display::open(Some(settings)).unwrap();
 		display
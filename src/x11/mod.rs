@@ -1,7 +1,8 @@
 pub mod display;
 pub mod window;
+pub mod xvfb;
 
-use crate::record::settings::RecordWindow;
+use crate::record::settings::{RecordWindow, StartTrigger};
 use crate::settings::AppSettings;
 use crate::window::Access;
 use crate::x11::display::Display;
@@ -25,6 +26,9 @@ impl<'a> Access<'a, Window> for WindowSystem<'a> {
 	 * @return WindowSystem (Option)
 	 */
 	fn init(settings: &'a AppSettings<'a>) -> Option<Self> {
+		if settings.multi.is_enabled() {
+			unsafe { xlib::XInitThreads() };
+		}
 		if let Some(display) = Display::open(Some(settings.record)) {
 			unsafe { xlib::XSetErrorHandler(Some(handle_x11_errors)) };
 			Some(Self { display, settings })
@@ -41,6 +45,12 @@ impl<'a> Access<'a, Window> for WindowSystem<'a> {
 	 */
 	fn get_window(&mut self) -> Option<Window> {
 		debug!("Record window: {:?}", self.settings.record.window);
+		if let Some(StartTrigger::Window(name)) = self.settings.record.trigger.start
+		{
+			return self
+				.display
+				.wait_for_window(name, self.settings.input_state);
+		}
 		match self.settings.record.window {
 			RecordWindow::Focus(None, parent) => {
 				self.display.get_focused_window(parent)
@@ -64,6 +74,25 @@ impl<'a> Access<'a, Window> for WindowSystem<'a> {
 			}
 		}
 	}
+
+	/**
+	 * Get the windows to record with the given IDs or names.
+	 *
+	 * @param  targets
+	 * @return Vector of Window
+	 */
+	fn get_windows(&mut self, targets: &[String]) -> Vec<Window> {
+		targets
+			.iter()
+			.filter_map(|target| {
+				let window = self.display.get_window_by_target(target);
+				if window.is_none() {
+					error!("Cannot find the window: {:?}", target);
+				}
+				window
+			})
+			.collect()
+	}
 }
 
 /* X opcodes to trace */
@@ -419,6 +419,30 @@ impl Capture for Window {
 		}
 	}
 
+	/**
+	 * Get the position of the recording area on the root window.
+	 *
+	 * @return Tuple of x and y
+	 */
+	fn get_position(&self) -> (i32, i32) {
+		let (mut x, mut y, mut child) = (0, 0, 0);
+		unsafe {
+			xlib::XTranslateCoordinates(
+				self.display.inner,
+				self.xid,
+				xlib::XRootWindowOfScreen(xlib::XDefaultScreenOfDisplay(
+					self.display.inner,
+				)),
+				self.area.x,
+				self.area.y,
+				&mut x,
+				&mut y,
+				&mut child,
+			);
+		}
+		(x, y)
+	}
+
 	/* Show a countdown on the corner of window. */
 	fn show_countdown(&self) {
 		if self.display.settings.time.countdown != 0 {
@@ -474,7 +498,7 @@ mod tests {
 	#[test]
 	fn test_x11_window() {
 		let mut settings = RecordSettings::default();
-		settings.time = RecordTime::new(Some(0.0), 1, 0, 10);
+		settings.time = RecordTime::new(Some(0.0), 1, 0, 10, None);
 		let display = Display::open(Some(settings)).unwrap();
 		let window = display.get_root_window();
 		unsafe {
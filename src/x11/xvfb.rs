@@ -0,0 +1,170 @@
+use crate::image::geometry::Geometry;
+use std::env;
+use std::io::{self, Error, ErrorKind, Read};
+use std::os::unix::process::CommandExt;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/* Executable of the virtual X server */
+const XVFB_COMMAND: &str = "Xvfb";
+/* First display number to try for the virtual display */
+const DISPLAY_OFFSET: u32 = 99;
+/* Timeout for waiting the virtual display to be ready */
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/* Private virtual X display that runs on Xvfb */
+#[derive(Debug)]
+pub struct VirtualDisplay {
+	number: u32,
+	process: Child,
+}
+
+impl VirtualDisplay {
+	/**
+	 * Start a virtual display with the given size and use it as DISPLAY.
+	 *
+	 * @param  size
+	 * @return VirtualDisplay (Result)
+	 */
+	pub fn start(size: Geometry) -> io::Result<Self> {
+		let number = (DISPLAY_OFFSET..DISPLAY_OFFSET + 100)
+			.find(|number| {
+				!Self::get_lock_path(*number).exists()
+					&& !Self::get_socket_path(*number).exists()
+			})
+			.ok_or_else(|| {
+				Error::new(ErrorKind::Other, "No display number available")
+			})?;
+		let mut command = Command::new(XVFB_COMMAND);
+		command
+			.arg(format!(":{}", number))
+			.args(["-screen", "0"])
+			.arg(format!("{}x{}x24", size.width, size.height))
+			.args(["-nolisten", "tcp"])
+			.stdout(Stdio::null())
+			.stderr(Stdio::piped());
+		unsafe {
+			command.pre_exec(|| {
+				if libc::setpgid(0, 0) == -1 {
+					return Err(io::Error::last_os_error());
+				}
+				Ok(())
+			});
+		}
+		let mut process = command
+			.spawn()
+			.map_err(|e| Error::new(e.kind(), format!("{}: {}", XVFB_COMMAND, e)))?;
+		let stderr = process.stderr.take().map(|mut stderr| {
+			thread::spawn(move || {
+				let mut output = String::new();
+				stderr.read_to_string(&mut output).map(|_| output)
+			})
+		});
+		let mut display = Self { number, process };
+		display.wait_until_ready(stderr)?;
+		env::set_var("DISPLAY", display.get_name());
+		info!(
+			"Started the virtual display {} [{}x{}]",
+			display.get_name(),
+			size.width,
+			size.height
+		);
+		Ok(display)
+	}
+
+	/**
+	 * Get the lock file path of the given display.
+	 *
+	 * @param  number
+	 * @return PathBuf
+	 */
+	fn get_lock_path(number: u32) -> PathBuf {
+		PathBuf::from(format!("/tmp/.X{}-lock", number))
+	}
+
+	/**
+	 * Get the socket path of the given display.
+	 *
+	 * @param  number
+	 * @return PathBuf
+	 */
+	fn get_socket_path(number: u32) -> PathBuf {
+		PathBuf::from(format!("/tmp/.X11-unix/X{}", number))
+	}
+
+	/**
+	 * Get the name of the display.
+	 *
+	 * @return String
+	 */
+	pub fn get_name(&self) -> String {
+		format!(":{}", self.number)
+	}
+
+	/**
+	 * Wait until the display accepts connections.
+	 *
+	 * @param  stderr (Option)
+	 * @return Result
+	 */
+	fn wait_until_ready(
+		&mut self,
+		stderr: Option<JoinHandle<io::Result<String>>>,
+	) -> io::Result<()> {
+		let start_time = Instant::now();
+		while !Self::get_socket_path(self.number).exists() {
+			if let Some(status) = self.process.try_wait()? {
+				let stderr = stderr
+					.and_then(|thread| thread.join().ok())
+					.and_then(|output| output.ok())
+					.unwrap_or_default();
+				return Err(Error::new(
+					ErrorKind::Other,
+					format!(
+						"{} exited with {}: {}",
+						XVFB_COMMAND,
+						status,
+						stderr.trim()
+					),
+				));
+			} else if start_time.elapsed() > STARTUP_TIMEOUT {
+				return Err(Error::new(
+					ErrorKind::TimedOut,
+					format!("{} is not ready", XVFB_COMMAND),
+				));
+			}
+			thread::sleep(Duration::from_millis(10));
+		}
+		Ok(())
+	}
+}
+
+/* Stop the virtual X server */
+impl Drop for VirtualDisplay {
+	fn drop(&mut self) {
+		if let Err(e) = self.process.kill().and_then(|_| self.process.wait()) {
+			error!("Failed to stop the virtual display: {}", e);
+		} else {
+			debug!("Stopped the virtual display {}", self.get_name());
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_xvfb_paths() {
+		assert_eq!(
+			PathBuf::from("/tmp/.X99-lock"),
+			VirtualDisplay::get_lock_path(99)
+		);
+		assert_eq!(
+			PathBuf::from("/tmp/.X11-unix/X99"),
+			VirtualDisplay::get_socket_path(99)
+		);
+	}
+}
@@ -0,0 +1,223 @@
+pub mod settings;
+
+use crate::anim::settings::AnimSettings;
+use crate::app::{AppError, AppResult};
+use crate::image::Image;
+use crate::util::state::InputState;
+use crate::y4m::settings::{Chroma, Y4mSettings};
+use image::ExtendedColorType;
+use std::io::{self, Write};
+use y4m::{Encoder, Frame, Ratio};
+
+/* YUV4MPEG2 stream encoder and settings */
+pub struct Y4mEncoder<Output: Write> {
+	fps: u32,
+	output: Option<Output>,
+	encoder: Option<Encoder<Output>>,
+	settings: Y4mSettings,
+	elapsed: f64,
+	frame_count: u64,
+}
+
+impl<Output: Write> Y4mEncoder<Output> {
+	/**
+	 * Create a new Y4mEncoder object.
+	 *
+	 * @param  fps
+	 * @param  output
+	 * @param  settings
+	 * @return Y4mEncoder
+	 */
+	pub fn new(fps: u32, output: Output, settings: &AnimSettings) -> Self {
+		Self {
+			fps: fps.max(1),
+			output: Some(output),
+			encoder: None,
+			settings: settings.y4m,
+			elapsed: 0.,
+			frame_count: 0,
+		}
+	}
+
+	/**
+	 * Encode images as frame and write to the Y4M stream.
+	 *
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	pub fn save(
+		mut self,
+		images: Vec<Image>,
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		for (i, image) in images.iter().enumerate() {
+			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
+			info!("Saving... ({:.1}%)\r", percentage);
+			debug!(
+				"Encoding... ({:.1}%) [{}/{}]\r",
+				percentage,
+				i + 1,
+				images.len()
+			);
+			io::stdout().flush()?;
+			if let Some(state) = input_state {
+				if state.check_cancel_keys() {
+					info!("\n");
+					warn!("User interrupt detected.");
+					panic!("Failed to write the frames")
+				}
+			}
+			self.add(image)?;
+		}
+		info!("\n");
+		self.finish()
+	}
+
+	/**
+	 * Finish the stream after checking that its header is written.
+	 *
+	 * @return Result
+	 */
+	pub fn finish(self) -> AppResult<()> {
+		if self.encoder.is_none() {
+			return Err(AppError::FrameError(String::from(
+				"No frames found to write the Y4M stream",
+			)));
+		}
+		Ok(())
+	}
+
+	/**
+	 * Write the image to the stream as many times as its delay lasts.
+	 * The stream header is written before the first frame.
+	 *
+	 * @param  image
+	 * @return Result
+	 */
+	pub fn add(&mut self, image: &Image) -> AppResult<()> {
+		let planes = self.get_planes(image);
+		let encoder = match self.encoder.as_mut() {
+			Some(encoder) => encoder,
+			None => {
+				let output = self.output.take().ok_or_else(|| {
+					AppError::FrameError(String::from("Failed to get the output"))
+				})?;
+				self.encoder.insert(
+					y4m::encode(
+						image.geometry.width as usize,
+						image.geometry.height as usize,
+						Ratio::new(self.fps as usize, 1),
+					)
+					.with_colorspace(self.settings.get_colorspace())
+					.write_header(output)?,
+				)
+			}
+		};
+		self.elapsed += image
+			.delay
+			.map_or(1000. / self.fps as f64, |delay| delay as f64);
+		let frame_count = (self.elapsed * self.fps as f64 / 1000.).round() as u64;
+		let frame = Frame::new([&planes[0], &planes[1], &planes[2]], None);
+		while self.frame_count < frame_count {
+			encoder.write_frame(&frame)?;
+			self.frame_count += 1;
+		}
+		Ok(())
+	}
+
+	/**
+	 * Get the Y, U and V planes of the image (BT.601).
+	 *
+	 * @param  image
+	 * @return Array of Vector of u8
+	 */
+	fn get_planes(&self, image: &Image) -> [Vec<u8>; 3] {
+		let width = image.geometry.width as usize;
+		let height = image.geometry.height as usize;
+		let mut planes = [
+			Vec::with_capacity(width * height),
+			Vec::with_capacity(width * height),
+			Vec::with_capacity(width * height),
+		];
+		for rgb in image.get_data(ExtendedColorType::Rgb8).chunks(3) {
+			let (r, g, b) = (rgb[0] as f32, rgb[1] as f32, rgb[2] as f32);
+			planes[0].push((16. + 0.257 * r + 0.504 * g + 0.098 * b).round() as u8);
+			planes[1].push((128. - 0.148 * r - 0.291 * g + 0.439 * b).round() as u8);
+			planes[2].push((128. + 0.439 * r - 0.368 * g - 0.071 * b).round() as u8);
+		}
+		if self.settings.chroma == Chroma::C420 {
+			for plane in planes.iter_mut().skip(1) {
+				*plane = Self::subsample(plane, width, height);
+			}
+		}
+		planes
+	}
+
+	/**
+	 * Subsample the chroma plane by averaging the 2x2 blocks.
+	 *
+	 * @param  plane
+	 * @param  width
+	 * @param  height
+	 * @return Vector of u8
+	 */
+	fn subsample(plane: &[u8], width: usize, height: usize) -> Vec<u8> {
+		let mut samples = Vec::new();
+		for y in (0..height).step_by(2) {
+			for x in (0..width).step_by(2) {
+				let block = [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]
+					.iter()
+					.filter(|(x, y)| *x < width && *y < height)
+					.map(|(x, y)| plane[y * width + x] as u32)
+					.collect::<Vec<u32>>();
+				let count = block.len() as u32;
+				samples
+					.push(((block.iter().sum::<u32>() + count / 2) / count) as u8);
+			}
+		}
+		samples
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::Bgra;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_y4m_encoder() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 3, 1);
+		let mut images = vec![
+			Image::new(vec![Bgra::from([255, 255, 255, 255]); 3], false, geometry),
+			Image::new(vec![Bgra::from([0, 0, 0, 255]); 3], false, geometry),
+		];
+		images[1].delay = Some(250);
+		let mut output = Vec::new();
+		Y4mEncoder::new(10, &mut output, &AnimSettings::default())
+			.save(images, None)?;
+		let mut decoder = y4m::decode(output.as_slice())?;
+		assert_eq!(10, decoder.get_framerate().num);
+		let mut frames = Vec::new();
+		while let Ok(frame) = decoder.read_frame() {
+			frames.push(
+				[
+					frame.get_y_plane(),
+					frame.get_u_plane(),
+					frame.get_v_plane(),
+				]
+				.concat(),
+			);
+		}
+		assert_eq!(4, frames.len());
+		assert_eq!(vec![235, 235, 235, 128, 128, 128, 128], frames[0]);
+		assert_eq!(vec![16, 16, 16, 128, 128, 128, 128], frames[3]);
+		assert!(matches!(
+			Y4mEncoder::new(10, Vec::new(), &AnimSettings::default())
+				.save(Vec::new(), None),
+			Err(AppError::FrameError(_))
+		));
+		Ok(())
+	}
+}
@@ -0,0 +1,81 @@
+use y4m::Colorspace;
+
+/* Chroma subsampling of the YUV frames */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Chroma {
+	C420,
+	C444,
+}
+
+impl Chroma {
+	/**
+	 * Parse Chroma from a string.
+	 *
+	 * @param  chroma
+	 * @return Chroma
+	 */
+	pub fn parse(chroma: &str) -> Self {
+		match chroma {
+			"444" => Self::C444,
+			_ => Self::C420,
+		}
+	}
+}
+
+/* Y4M encoding settings */
+#[derive(Clone, Copy, Debug)]
+pub struct Y4mSettings {
+	pub chroma: Chroma,
+}
+
+/* Default initialization values for Y4mSettings */
+impl Default for Y4mSettings {
+	fn default() -> Self {
+		Self {
+			chroma: Chroma::C420,
+		}
+	}
+}
+
+impl Y4mSettings {
+	/**
+	 * Create a new Y4mSettings object.
+	 *
+	 * @param  chroma
+	 * @return Y4mSettings
+	 */
+	pub fn new(chroma: Chroma) -> Self {
+		Self { chroma }
+	}
+
+	/**
+	 * Get the colorspace of the stream.
+	 *
+	 * @return Colorspace
+	 */
+	pub fn get_colorspace(&self) -> Colorspace {
+		match self.chroma {
+			Chroma::C420 => Colorspace::C420jpeg,
+			Chroma::C444 => Colorspace::C444,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_y4m_settings() {
+		assert_eq!(Chroma::C444, Chroma::parse("444"));
+		assert_eq!(Chroma::C420, Chroma::parse("420"));
+		assert!(matches!(
+			Y4mSettings::default().get_colorspace(),
+			Colorspace::C420jpeg
+		));
+		assert!(matches!(
+			Y4mSettings::new(Chroma::C444).get_colorspace(),
+			Colorspace::C444
+		));
+	}
+}